@@ -10,7 +10,10 @@ pub trait Board {
      * Required methods
      */
     fn get_num_columns(&self) -> usize;
+    // Every row, including the hidden ones at the top
     fn get_num_rows(&self) -> usize;
+    // Rows shown to the player, at the bottom of the board
+    fn get_num_visible_rows(&self) -> usize;
     fn is_occupied(&self, position: &BoardPosition) -> bool;
     fn is_touching_wall(&self, position: &BoardPosition, wall_direction: &Direction) -> bool;
     fn is_in_bounds(&self, position: &BoardPosition) -> bool;
@@ -23,11 +26,23 @@ pub trait Board {
     fn get_filled_rows(&self) -> Vec<usize>;
     fn clear_rows(&mut self, rows: &[usize], settings: &Settings);
 
+    /**
+     * Pushes the stack up and fills the bottom with rows that have a single
+     * empty tile. A hole column past the right wall is moved to the last
+     * column, so that garbage can always be cleared. Returns false if blocks
+     * got pushed past the top.
+     */
+    fn push_garbage_rows(&mut self, num_rows: usize, hole_column: usize) -> bool;
+
     fn for_each_row(&self, callback: &mut FnMut(&Vec<&Option<Block>>));
 
     /**
      * Provided methods
      */
+    fn get_num_hidden_rows(&self) -> usize {
+        self.get_num_rows() - self.get_num_visible_rows()
+    }
+
     fn clear_filled_rows(&mut self, settings: &Settings) {
         let filled_rows = self.get_filled_rows();
 
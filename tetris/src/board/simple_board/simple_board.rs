@@ -1,6 +1,7 @@
 use super::super::super::core::Direction;
 use super::super::super::helpers;
-use super::super::super::piece::{Piece, PieceColor};
+use super::super::super::piece::{ColorScheme, Piece, PieceColor, PieceKind};
+use super::super::super::piece::color_scheme::GuidelineColorScheme;
 use super::super::super::position::BoardPosition;
 use super::super::super::rotations::RotationSystem;
 use super::super::super::settings::Settings;
@@ -10,6 +11,7 @@ use super::row_iterator::RowIterator;
 pub struct SimpleBoard {
     grid: Vec<Option<Block>>,
     num_columns: usize,
+    num_hidden_rows: usize,
 }
 
 struct PositionOutOfBounds;
@@ -26,9 +28,30 @@ impl SimpleBoard {
         SimpleBoard {
             grid,
             num_columns,
+            num_hidden_rows: 0,
         }
     }
 
+    /**
+     * Creates a board with a buffer of hidden rows above the visible ones,
+     * where pieces spawn and the stack can grow out of view.
+     */
+    pub fn with_hidden_rows(
+        num_columns: usize,
+        num_visible_rows: usize,
+        num_hidden_rows: usize,
+    ) -> SimpleBoard {
+        let mut board = SimpleBoard::new(num_columns, num_visible_rows + num_hidden_rows);
+        board.num_hidden_rows = num_hidden_rows;
+
+        board
+    }
+
+    /**
+     * Builds a board from strings where '0' is an empty tile, '1' is a
+     * block and a piece kind letter (e.g. 'T') is a block with the
+     * guideline color of that kind.
+     */
     pub fn from_array(rows: &[&str]) -> SimpleBoard {
         let num_rows = rows.len();
 
@@ -45,7 +68,9 @@ impl SimpleBoard {
                     '1' => grid.push(Some(Block {
                         color: PieceColor::Blue,
                     })),
-                    _ => panic!("Invalid board construction data"),
+                    _ => grid.push(Some(Block {
+                        color: GuidelineColorScheme.get_color(&parse_piece_kind(column), 0),
+                    })),
                 }
             }
         }
@@ -53,6 +78,7 @@ impl SimpleBoard {
         SimpleBoard {
             grid,
             num_columns,
+            num_hidden_rows: 0,
         }
     }
 
@@ -68,6 +94,68 @@ impl SimpleBoard {
         RowIterator::new(self)
     }
 
+    /**
+     * Removes the given rows, shifting everything above them down and
+     * filling the top of the board with empty rows.
+     */
+    pub fn remove_rows(&mut self, rows: &[usize]) {
+        let num_columns = self.num_columns;
+        let num_removed_rows = (0..self.get_num_rows())
+            .filter(|row| rows.contains(row))
+            .count();
+
+        let mut grid = Vec::with_capacity(self.grid.len());
+
+        for _ in 0..(num_removed_rows * num_columns) {
+            grid.push(None);
+        }
+
+        grid.extend(
+            self.grid
+                .drain(..)
+                .enumerate()
+                .filter(|(index, _)| !rows.contains(&(index / num_columns)))
+                .map(|(_, tile)| tile)
+        );
+
+        self.grid = grid;
+    }
+
+    /**
+     * Empties the given rows without moving any other block.
+     */
+    pub fn empty_rows(&mut self, rows: &[usize]) {
+        let num_columns = self.num_columns;
+
+        self.grid
+            .iter_mut()
+            .enumerate()
+            .filter(|(index, _)| rows.contains(&(index / num_columns)))
+            .for_each(|(_, tile)| *tile = None);
+    }
+
+    /**
+     * Removes the block at a given position, if any. Returns None if the
+     * position is out of bounds.
+     */
+    pub fn take_block(&mut self, position: &BoardPosition) -> Option<Block> {
+        match self.at_mut(position) {
+            Ok(tile) => tile.take(),
+            Err(_) => None,
+        }
+    }
+
+    /**
+     * Places a block at a given position, replacing whatever was there.
+     * Panics if the position is out of bounds.
+     */
+    pub fn put_block(&mut self, position: &BoardPosition, block: Block) {
+        match self.at_mut(position) {
+            Ok(tile) => *tile = Some(block),
+            Err(_) => panic!("Block placed out of bounds"),
+        }
+    }
+
     fn at(&self, position: &BoardPosition) -> Result<&Option<Block>, PositionOutOfBounds> {
         let index_opt = self.position_to_index(position);
 
@@ -112,6 +200,10 @@ impl Board for SimpleBoard {
         self.grid.len() / self.num_columns
     }
 
+    fn get_num_visible_rows(&self) -> usize {
+        self.get_num_rows() - self.num_hidden_rows
+    }
+
     /**
      * Checks if a given position is occupied by a block. Returns false if
      * the position is out of bounds.
@@ -180,8 +272,27 @@ impl Board for SimpleBoard {
             .collect()
     }
 
-    fn clear_rows(&mut self, _rows: &[usize], _settings: &Settings) {
-        // TODO
+    fn clear_rows(&mut self, rows: &[usize], _settings: &Settings) {
+        self.remove_rows(rows);
+    }
+
+    fn push_garbage_rows(&mut self, num_rows: usize, hole_column: usize) -> bool {
+        let num_columns = self.num_columns;
+        let hole_column = hole_column.min(num_columns - 1);
+        let num_pushed_tiles = (num_rows * num_columns).min(self.grid.len());
+        let has_overflowed = self.grid[..num_pushed_tiles].iter().any(|tile| tile.is_some());
+
+        self.grid.drain(..num_pushed_tiles);
+
+        for index in 0..num_pushed_tiles {
+            if index % num_columns == hole_column {
+                self.grid.push(None);
+            } else {
+                self.grid.push(Some(Block { color: PieceColor::Blue }));
+            }
+        }
+
+        !has_overflowed
     }
 
     fn for_each_row(&self, callback: &mut FnMut(&Vec<&Option<Block>>)) {
@@ -191,3 +302,16 @@ impl Board for SimpleBoard {
             });
     }
 }
+
+fn parse_piece_kind(value: char) -> PieceKind {
+    match value {
+        'I' => PieceKind::I,
+        'J' => PieceKind::J,
+        'L' => PieceKind::L,
+        'O' => PieceKind::O,
+        'S' => PieceKind::S,
+        'T' => PieceKind::T,
+        'Z' => PieceKind::Z,
+        _ => panic!("Invalid board construction data"),
+    }
+}
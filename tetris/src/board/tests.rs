@@ -1,3 +1,6 @@
+use super::super::gravity::naive::NaiveGravity;
+use super::super::piece::PieceColor;
+use super::super::settings;
 use super::{Board, SimpleBoard};
 
 #[test]
@@ -9,6 +12,17 @@ fn simple_board_construction_new() {
     assert_eq!(board.len(), 450);
 }
 
+#[test]
+fn simple_board_construction_with_hidden_rows() {
+    let board = SimpleBoard::with_hidden_rows(10, 20, 20);
+
+    assert_eq!(board.get_num_columns(), 10);
+    assert_eq!(board.get_num_rows(), 40);
+    assert_eq!(board.get_num_visible_rows(), 20);
+    assert_eq!(board.get_num_hidden_rows(), 20);
+    assert_eq!(board.len(), 400);
+}
+
 #[test]
 fn simple_board_construction_from_array() {
     let board = SimpleBoard::from_array(&[
@@ -26,6 +40,26 @@ fn simple_board_construction_from_array() {
     assert_eq!(board.len(), 35);
 }
 
+#[test]
+fn simple_board_construction_from_array_with_colors() {
+    let board = SimpleBoard::from_array(&[
+        "0000",
+        "1TZI",
+    ]);
+
+    let colors: Vec<_> = board.tiles()
+        .map(|tile| tile.as_ref().map(|block| block.color.clone()))
+        .collect();
+
+    assert_eq!(colors[..4], [None, None, None, None]);
+    assert_eq!(colors[4..], [
+        Some(PieceColor::Blue),
+        Some(PieceColor::Purple),
+        Some(PieceColor::Red),
+        Some(PieceColor::Cyan),
+    ]);
+}
+
 #[test]
 fn filled_rows_are_detected() {
     let board = SimpleBoard::from_array(&[
@@ -40,3 +74,174 @@ fn filled_rows_are_detected() {
 
     assert_eq!(board.get_filled_rows(), vec![3, 4, 6]);
 }
+
+struct RowClearCase<'a> {
+    board: &'a [&'a str],
+    rows: &'a [usize],
+    expected: &'a [&'a str],
+}
+
+const ROW_CLEAR_CASES: &[RowClearCase] = &[
+    // single row at the bottom
+    RowClearCase {
+        board: &["00000", "10000", "11111"],
+        rows: &[2],
+        expected: &["00000", "00000", "10000"],
+    },
+    // single row at the top
+    RowClearCase {
+        board: &["11111", "10000", "11000"],
+        rows: &[0],
+        expected: &["00000", "10000", "11000"],
+    },
+    // non-adjacent rows
+    RowClearCase {
+        board: &["00100", "11111", "01100", "11111", "10001"],
+        rows: &[1, 3],
+        expected: &["00000", "00000", "00100", "01100", "10001"],
+    },
+    // non-adjacent rows, including both the top and the bottom
+    RowClearCase {
+        board: &["11111", "00010", "11111", "01000", "11111"],
+        rows: &[0, 2, 4],
+        expected: &["00000", "00000", "00000", "00010", "01000"],
+    },
+    // four-line clear
+    RowClearCase {
+        board: &["00000", "00100", "11111", "11111", "11111", "11111"],
+        rows: &[2, 3, 4, 5],
+        expected: &["00000", "00000", "00000", "00000", "00000", "00100"],
+    },
+    // four-line clear with leftovers below
+    RowClearCase {
+        board: &["01000", "11111", "11111", "11111", "11111", "10110"],
+        rows: &[1, 2, 3, 4],
+        expected: &["00000", "00000", "00000", "00000", "01000", "10110"],
+    },
+    // unsorted input
+    RowClearCase {
+        board: &["00001", "11111", "10000", "11111"],
+        rows: &[3, 1],
+        expected: &["00000", "00000", "00001", "10000"],
+    },
+    // no rows
+    RowClearCase {
+        board: &["00000", "10100"],
+        rows: &[],
+        expected: &["00000", "10100"],
+    },
+];
+
+#[test]
+fn simple_board_clears_rows() {
+    let settings = settings::make_default_settings();
+
+    for case in ROW_CLEAR_CASES {
+        let mut board = SimpleBoard::from_array(case.board);
+        board.clear_rows(case.rows, &settings);
+
+        assert_eq!(readable_board(&board), case.expected, "board: {:?}", case.board);
+    }
+}
+
+#[test]
+fn naive_gravity_clears_rows() {
+    let gravity = NaiveGravity::new();
+
+    for case in ROW_CLEAR_CASES {
+        let mut board = SimpleBoard::from_array(case.board);
+        gravity.clear_rows(&mut board, case.rows);
+
+        assert_eq!(readable_board(&board), case.expected, "board: {:?}", case.board);
+        assert_eq!(board.get_num_rows(), case.board.len());
+    }
+}
+
+#[test]
+fn naive_gravity_clears_detected_rows() {
+    let mut board = SimpleBoard::from_array(&[
+        "00000",
+        "10000",
+        "11000",
+        "11111",
+        "11111",
+        "10111",
+        "11111",
+    ]);
+
+    let filled_rows = board.get_filled_rows();
+    NaiveGravity::new().clear_rows(&mut board, &filled_rows);
+
+    assert_eq!(readable_board(&board), vec![
+        "00000",
+        "00000",
+        "00000",
+        "00000",
+        "10000",
+        "11000",
+        "10111",
+    ]);
+    assert_eq!(board.get_filled_rows(), vec![]);
+}
+
+#[test]
+fn garbage_rows_push_the_stack_up() {
+    let mut board = SimpleBoard::from_array(&[
+        "00000",
+        "00000",
+        "00000",
+        "01000",
+        "11011",
+    ]);
+
+    assert!(board.push_garbage_rows(2, 3));
+    assert_eq!(readable_board(&board), vec![
+        "00000",
+        "01000",
+        "11011",
+        "11101",
+        "11101",
+    ]);
+}
+
+#[test]
+fn garbage_rows_report_blocks_pushed_past_the_top() {
+    let mut board = SimpleBoard::from_array(&[
+        "00000",
+        "00100",
+        "11011",
+    ]);
+
+    assert!(!board.push_garbage_rows(2, 0));
+    assert_eq!(readable_board(&board), vec![
+        "11011",
+        "01111",
+        "01111",
+    ]);
+}
+
+#[test]
+fn garbage_holes_past_the_right_wall_go_to_the_last_column() {
+    let mut board = SimpleBoard::from_array(&[
+        "00000",
+        "00000",
+        "11011",
+    ]);
+
+    assert!(board.push_garbage_rows(1, 7));
+    assert_eq!(readable_board(&board), vec![
+        "00000",
+        "11011",
+        "11110",
+    ]);
+}
+
+fn readable_board(board: &SimpleBoard) -> Vec<String> {
+    board.rows()
+        .map(|row| {
+            row.iter()
+                .map(|tile| if tile.is_some() { '1' } else { '0' })
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,15 @@
+/**
+ * Something the player can do to the active piece, independently of the key
+ * that is bound to it.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    MoveLeft,
+    MoveRight,
+    RotateClockwise,
+    RotateCounterclockwise,
+    RotateHalf,
+    Hold,
+    SoftDrop,
+    HardDrop,
+}
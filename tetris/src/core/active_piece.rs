@@ -2,9 +2,10 @@ use lazy_static::lazy_static;
 use std::collections::HashMap;
 use super::super::board::{Board, MaterializationStatus};
 use super::super::helpers;
-use super::super::piece::{Piece, PieceColor};
+use super::super::piece::{Piece, PieceColor, PieceKind};
 use super::super::position::{BoardPosition, BoardPositionOffset};
 use super::super::rotations::{RotationDirection, RotationSystem};
+use super::super::scoring::Spin;
 use super::Direction;
 
 lazy_static! {
@@ -19,10 +20,16 @@ lazy_static! {
     };
 }
 
+const NEIGHBOR_OFFSETS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+const CORNER_OFFSETS: [(isize, isize); 4] = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+
 pub struct ActivePiece {
     piece: Piece,
     position: BoardPosition,
     rotation_system: RotationSystem,
+    has_instant_gravity: bool,
+    last_action_was_rotation: bool,
+    last_kick: Option<BoardPositionOffset>,
 }
 
 impl ActivePiece {
@@ -31,13 +38,48 @@ impl ActivePiece {
         position: BoardPosition,
         rotation_system: RotationSystem,
     ) -> ActivePiece {
-        ActivePiece { piece, position, rotation_system }
+        ActivePiece {
+            piece,
+            position,
+            rotation_system,
+            has_instant_gravity: false,
+            last_action_was_rotation: false,
+            last_kick: None,
+        }
+    }
+
+    /**
+     * Enables 20G: from now on, the piece drops to the ground right away
+     * and again after every successful move or rotation.
+     */
+    pub fn enable_instant_gravity(&mut self, board: &dyn Board) {
+        self.has_instant_gravity = true;
+        self.apply_instant_gravity(board);
+    }
+
+    pub fn has_instant_gravity(&self) -> bool {
+        self.has_instant_gravity
+    }
+
+    /**
+     * Checks if the piece is inside the board and clear of the stack.
+     */
+    pub fn fits(&self, board: &dyn Board) -> bool {
+        self.is_valid(board)
     }
 
     pub fn get_block_iterator<'b>(&'b self) -> impl Iterator<Item = BoardPosition> + 'b {
         helpers::get_piece_iterator(&self.piece, &self.position, &self.rotation_system)
     }
 
+    pub fn get_piece(&self) -> &Piece {
+        &self.piece
+    }
+
+    pub fn get_position(&self) -> &BoardPosition {
+        &self.position
+    }
+
     pub fn get_color(&self) -> &PieceColor {
         &self.piece.get_color()
     }
@@ -49,6 +91,10 @@ impl ActivePiece {
     pub fn transfer_rotation_system(self, into: &mut Option<RotationSystem>) {
         into.replace(self.rotation_system);
     }
+
+    pub fn into_parts(self) -> (Piece, RotationSystem) {
+        (self.piece, self.rotation_system)
+    }
 }
 
 /**
@@ -59,10 +105,15 @@ impl ActivePiece {
         &mut self,
         direction: Direction,
         board: &dyn Board,
-    ) {
-        if self.can_move_towards(&direction, board) {
-            self.move_towards(&direction);
+    ) -> bool {
+        if !self.can_move_towards(&direction, board) {
+            return false;
         }
+
+        self.move_towards(&direction);
+        self.apply_instant_gravity(board);
+
+        true
     }
 
     pub fn can_move_towards(
@@ -83,6 +134,37 @@ impl ActivePiece {
         let position_offset = DIRECTION_OFFSETS.get(&direction).unwrap();
 
         self.position += position_offset;
+        self.last_action_was_rotation = false;
+        self.last_kick = None;
+    }
+
+    /**
+     * Moves the piece a row up, no matter what is there, e.g. to make room
+     * for garbage coming from below.
+     */
+    pub fn lift(&mut self) {
+        self.position += &BoardPositionOffset::new(-1, 0);
+    }
+
+    /**
+     * Moves the piece down as far as it goes and returns the number of rows
+     * it fell.
+     */
+    pub fn hard_drop(&mut self, board: &dyn Board) -> usize {
+        let mut distance = 0;
+
+        while self.can_move_towards(&Direction::Down, board) {
+            self.move_towards(&Direction::Down);
+            distance += 1;
+        }
+
+        distance
+    }
+
+    fn apply_instant_gravity(&mut self, board: &dyn Board) {
+        if self.has_instant_gravity {
+            self.hard_drop(board);
+        }
     }
 }
 
@@ -90,33 +172,216 @@ impl ActivePiece {
  * Rotation-related methods
  */
 impl ActivePiece {
+    /**
+     * Rotates the piece if possible. If the rotated piece collides, the
+     * kicks of the rotation system are tried in order; if all of them
+     * fail, the rotation is reverted. Returns whether the piece rotated;
+     * turns that end in the same state, e.g. a half turn of a piece with
+     * one or two states, don't count.
+     */
     pub fn try_rotate(
         &mut self,
         direction: RotationDirection,
         board: &dyn Board,
-    ) {
+    ) -> bool {
+        self.try_rotate_with(direction, board, true)
+    }
+
+    /**
+     * Rotates the piece only if it fits right where it is, as the initial
+     * rotation does. Returns whether the piece rotated.
+     */
+    pub fn try_rotate_without_kicks(
+        &mut self,
+        direction: RotationDirection,
+        board: &dyn Board,
+    ) -> bool {
+        self.try_rotate_with(direction, board, false)
+    }
+
+    fn try_rotate_with(
+        &mut self,
+        direction: RotationDirection,
+        board: &dyn Board,
+        can_kick: bool,
+    ) -> bool {
+        let from_state = self.piece.get_rotation_index();
         self.rotate(&direction);
+        let to_state = self.piece.get_rotation_index();
+
+        if from_state == to_state {
+            return false;
+        }
+
+        if self.is_valid(board) {
+            self.on_rotated(None, board);
+            return true;
+        }
+
+        if !can_kick || self.is_blocked_at_center_column(board) {
+            self.rotate(&direction.reverse());
+            return false;
+        }
+
+        let original_position = self.position;
+        let kicks = self.rotation_system
+            .get_kicks(self.piece.get_kind(), from_state, to_state)
+            .to_vec();
 
-        if !self.is_valid(board) {
-            let reverse_direction = match direction {
-                RotationDirection::Clockwise => RotationDirection::Counterclockwise,
-                RotationDirection::Counterclockwise => RotationDirection::Clockwise,
-            };
+        for kick in &kicks {
+            self.position = original_position + kick;
 
-            self.rotate(&reverse_direction);
+            if self.is_valid(board) {
+                self.on_rotated(Some(*kick), board);
+                return true;
+            }
         }
+
+        self.position = original_position;
+        self.rotate(&direction.reverse());
+
+        false
+    }
+
+    /**
+     * Remembers how the piece got rotated, for spin detection. A drop
+     * caused by 20G is part of the rotation and does not count as a move.
+     */
+    fn on_rotated(&mut self, kick: Option<BoardPositionOffset>, board: &dyn Board) {
+        self.apply_instant_gravity(board);
+
+        self.last_action_was_rotation = true;
+        self.last_kick = kick;
     }
 
     fn rotate(&mut self, direction: &RotationDirection) {
         self.piece.rotate(direction, &self.rotation_system);
     }
 
+    /**
+     * Checks if the center column rule forbids kicking, i.e. if the first
+     * blocked cell in reading order is in the center column of the grid.
+     */
+    fn is_blocked_at_center_column(&self, board: &dyn Board) -> bool {
+        if !self.rotation_system.has_center_column_rule(self.piece.get_kind()) {
+            return false;
+        }
+
+        let grid_size = self.piece.get_grid(&self.rotation_system).0.len();
+        let center_column = (grid_size as f32).sqrt() as isize / 2;
+
+        self.get_block_iterator()
+            .find(|tile_position| {
+                !board.is_in_bounds(tile_position) || board.is_occupied(tile_position)
+            })
+            .map(|tile_position| tile_position.column - self.position.column == center_column)
+            .unwrap_or(false)
+    }
+
     fn is_valid(&self, board: &dyn Board) -> bool {
+        self.is_valid_with_offset(&BoardPositionOffset::new(0, 0), board)
+    }
+
+    fn is_valid_with_offset(&self, offset: &BoardPositionOffset, board: &dyn Board) -> bool {
         self.get_block_iterator()
-            .all(|tile_position| {
-                board.is_in_bounds(&tile_position) && !board.is_occupied(&tile_position)
+            .all(|tile_position| is_free(&(tile_position + offset), board))
+    }
+}
+
+/**
+ * Spin detection
+ */
+impl ActivePiece {
+    /**
+     * Classifies how the piece got to its current position. Only pieces
+     * whose last successful action was a rotation can be spins. T pieces
+     * follow the 3-corner and front-corner rules; with `detect_all_spins`,
+     * other pieces that cannot move in any direction count as mini spins.
+     */
+    pub fn detect_spin(&self, board: &dyn Board, detect_all_spins: bool) -> Spin {
+        if !self.last_action_was_rotation {
+            return Spin::None;
+        }
+
+        if *self.piece.get_kind() == PieceKind::T {
+            return self.detect_t_spin(board);
+        }
+
+        if detect_all_spins && self.is_immobile(board) {
+            return Spin::Mini;
+        }
+
+        Spin::None
+    }
+
+    /**
+     * A T piece with at least three of the four cells diagonal to its center
+     * occupied is a T-spin. It is a full one if both corners on the pointing
+     * side are occupied, or if the rotation used a kick two rows high (such
+     * as the last SRS test); otherwise it is a mini.
+     */
+    fn detect_t_spin(&self, board: &dyn Board) -> Spin {
+        let blocks: Vec<BoardPosition> = self.get_block_iterator().collect();
+        let has_block = |row: isize, column: isize| {
+            blocks.contains(&BoardPosition::new(row, column))
+        };
+
+        let center = blocks.iter().find(|block| {
+            NEIGHBOR_OFFSETS.iter()
+                .filter(|(row, column)| has_block(block.row + row, block.column + column))
+                .count() == 3
+        });
+
+        let center = match center {
+            Some(center) => center,
+            None => return Spin::None,
+        };
+
+        // The pointing side is opposite to the missing neighbor
+        let (back_row, back_column) = NEIGHBOR_OFFSETS.iter()
+            .find(|(row, column)| !has_block(center.row + row, center.column + column))
+            .unwrap();
+        let (front_row, front_column) = (-back_row, -back_column);
+
+        let occupied_corners: Vec<&(isize, isize)> = CORNER_OFFSETS.iter()
+            .filter(|(row, column)| {
+                let corner = BoardPosition::new(center.row + row, center.column + column);
+
+                !is_free(&corner, board)
             })
+            .collect();
+
+        if occupied_corners.len() < 3 {
+            return Spin::None;
+        }
+
+        let num_occupied_front_corners = occupied_corners.iter()
+            .filter(|(row, column)| {
+                (front_row != 0 && *row == front_row) || (front_column != 0 && *column == front_column)
+            })
+            .count();
+
+        // Only the last SRS test moves the piece both two rows and sideways
+        let used_long_kick = self.last_kick
+            .is_some_and(|kick| kick.get_row().abs() == 2 && kick.get_column() != 0);
+
+        if num_occupied_front_corners == 2 || used_long_kick {
+            Spin::Full
+        } else {
+            Spin::Mini
+        }
     }
+
+    fn is_immobile(&self, board: &dyn Board) -> bool {
+        NEIGHBOR_OFFSETS.iter()
+            .all(|(row, column)| {
+                !self.is_valid_with_offset(&BoardPositionOffset::new(*row, *column), board)
+            })
+    }
+}
+
+fn is_free(position: &BoardPosition, board: &dyn Board) -> bool {
+    board.is_in_bounds(position) && !board.is_occupied(position)
 }
 
 /**
@@ -0,0 +1,134 @@
+use super::{Action, Direction, Handling, InputHandler};
+
+/**
+ * Turns held Left/Right keys into horizontal moves, following the DAS and ARR
+ * of the handling settings.
+ */
+pub struct AutoShift {
+    handling: Handling,
+    direction: Option<Direction>,
+    charge_nanos: u64,
+    num_moves: u64,
+}
+
+impl AutoShift {
+    pub fn new(handling: Handling) -> AutoShift {
+        AutoShift {
+            handling,
+            direction: None,
+            charge_nanos: 0,
+            num_moves: 0,
+        }
+    }
+
+    /**
+     * Returns the direction the piece should move towards this frame and
+     * how many cells. With an ARR of zero the count is unbounded and the
+     * piece should move until it is blocked.
+     */
+    pub fn tick(
+        &mut self,
+        input_handler: &InputHandler,
+        elapsed_time_nanos: u64,
+    ) -> Option<(Direction, usize)> {
+        let is_left_pressed = input_handler.is_pressed(Action::MoveLeft);
+        let is_right_pressed = input_handler.is_pressed(Action::MoveRight);
+
+        // A fresh press always takes over, cancelling any charge towards the
+        // other direction
+        if is_left_pressed != is_right_pressed {
+            let direction = if is_left_pressed { Direction::Left } else { Direction::Right };
+            self.start(direction);
+
+            return Some((direction, 1));
+        }
+
+        let direction = self.direction?;
+
+        if !input_handler.is_held(get_action(direction)) {
+            let other_direction = get_opposite(direction);
+
+            if input_handler.is_held(get_action(other_direction)) {
+                self.start(other_direction);
+            } else {
+                self.direction = None;
+            }
+
+            return None;
+        }
+
+        self.charge_nanos += elapsed_time_nanos;
+
+        let num_moves = self.get_num_moves_until_now(elapsed_time_nanos);
+
+        // Instant moves keep pushing the piece every frame, so that it
+        // slides as soon as the way is clear
+        if num_moves == u64::MAX {
+            return Some((direction, usize::MAX));
+        }
+
+        let num_new_moves = num_moves - self.num_moves;
+        self.num_moves = num_moves;
+
+        if num_new_moves == 0 {
+            None
+        } else {
+            Some((direction, num_new_moves as usize))
+        }
+    }
+
+    /**
+     * Called when a new piece spawns. A preserved charge lets the new piece
+     * start repeating right away; otherwise DAS has to charge again.
+     */
+    pub fn on_spawn(&mut self, frame_duration_nanos: u64) {
+        let das_nanos = self.handling.das.to_nanos(frame_duration_nanos);
+
+        if self.handling.preserve_das_charge {
+            self.charge_nanos = self.charge_nanos.min(das_nanos);
+        } else {
+            self.charge_nanos = 0;
+        }
+
+        self.num_moves = 0;
+    }
+
+    fn start(&mut self, direction: Direction) {
+        self.direction = Some(direction);
+        self.charge_nanos = 0;
+        self.num_moves = 0;
+    }
+
+    /**
+     * Number of auto-repeated moves since DAS got charged, counting the one
+     * made as soon as it is charged. `u64::MAX` stands for instant moves.
+     */
+    fn get_num_moves_until_now(&self, frame_duration_nanos: u64) -> u64 {
+        let das_nanos = self.handling.das.to_nanos(frame_duration_nanos);
+        let arr_nanos = self.handling.arr.to_nanos(frame_duration_nanos);
+
+        if self.charge_nanos < das_nanos {
+            return 0;
+        }
+
+        if arr_nanos == 0 {
+            return u64::MAX;
+        }
+
+        1 + (self.charge_nanos - das_nanos) / arr_nanos
+    }
+}
+
+fn get_action(direction: Direction) -> Action {
+    match direction {
+        Direction::Left => Action::MoveLeft,
+        _ => Action::MoveRight,
+    }
+}
+
+fn get_opposite(direction: Direction) -> Direction {
+    match direction {
+        Direction::Left => Direction::Right,
+        _ => Direction::Left,
+    }
+}
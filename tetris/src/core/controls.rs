@@ -0,0 +1,46 @@
+use sfml::window::Key;
+use super::Action;
+
+/**
+ * Key bindings for every player action.
+ */
+pub struct Controls {
+    pub move_left: Key,
+    pub move_right: Key,
+    pub rotate_clockwise: Key,
+    pub rotate_counterclockwise: Key,
+    pub rotate_half: Key,
+    pub hold: Key,
+    pub soft_drop: Key,
+    pub hard_drop: Key,
+}
+
+impl Controls {
+    pub fn get_bindings(&self) -> [(Key, Action); 8] {
+        [
+            (self.move_left, Action::MoveLeft),
+            (self.move_right, Action::MoveRight),
+            (self.rotate_clockwise, Action::RotateClockwise),
+            (self.rotate_counterclockwise, Action::RotateCounterclockwise),
+            (self.rotate_half, Action::RotateHalf),
+            (self.hold, Action::Hold),
+            (self.soft_drop, Action::SoftDrop),
+            (self.hard_drop, Action::HardDrop),
+        ]
+    }
+
+    pub fn get_key(&self, action: Action) -> Key {
+        self.get_bindings()
+            .iter()
+            .find(|(_, bound_action)| *bound_action == action)
+            .map(|(key, _)| *key)
+            .unwrap()
+    }
+
+    pub fn get_action(&self, key: Key) -> Option<Action> {
+        self.get_bindings()
+            .iter()
+            .find(|(bound_key, _)| *bound_key == key)
+            .map(|(_, action)| *action)
+    }
+}
@@ -1,5 +1,5 @@
 // TODO: find a better folder for this
-#[derive(PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Direction {
     Left,
     Right,
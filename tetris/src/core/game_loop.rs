@@ -33,6 +33,13 @@ where
         self.update_period = NANOSECONDS_PER_SECOND / (ticks_per_second as u64);
     }
 
+    /**
+     * What the loop updates, e.g. to read the final state once it stopped.
+     */
+    pub fn get_update(&self) -> &TUpdate {
+        &self.update
+    }
+
     pub fn start(&mut self) {
         let mut last_measured_time = Instant::now();
         let mut accumulator = 0;
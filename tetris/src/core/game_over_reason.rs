@@ -0,0 +1,15 @@
+/**
+ * Why a game ended: one of the guideline's losing conditions, or the game
+ * mode's goal.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameOverReason {
+    // A new piece overlaps the stack when it spawns
+    BlockOut,
+    // A piece locks entirely above the visible area
+    LockOut,
+    // Garbage pushes blocks past the top of the board
+    TopOut,
+    // The game mode has been completed, e.g. the last Marathon level
+    GoalReached,
+}
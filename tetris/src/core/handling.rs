@@ -0,0 +1,37 @@
+const NANOSECONDS_PER_MILLISECOND: f64 = 1_000_000.;
+
+/**
+ * A handling delay, either counted in frames or in wall-clock time.
+ * Fractional frame counts are allowed, e.g. for sub-frame auto repeat.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HandlingDelay {
+    Frames(f32),
+    Milliseconds(f32),
+}
+
+impl HandlingDelay {
+    pub fn to_nanos(&self, frame_duration_nanos: u64) -> u64 {
+        match self {
+            HandlingDelay::Frames(frames) => {
+                (*frames as f64 * frame_duration_nanos as f64) as u64
+            },
+            HandlingDelay::Milliseconds(millis) => {
+                (*millis as f64 * NANOSECONDS_PER_MILLISECOND) as u64
+            },
+        }
+    }
+}
+
+/**
+ * Horizontal movement tuning.
+ *
+ * `das` (Delayed Auto Shift) is how long a direction must be held before the
+ * piece starts repeating; `arr` (Auto Repeat Rate) is the time between
+ * repeats afterwards, with zero moving the piece straight to the wall.
+ */
+pub struct Handling {
+    pub das: HandlingDelay,
+    pub arr: HandlingDelay,
+    pub preserve_das_charge: bool,
+}
@@ -1,46 +1,61 @@
-use sfml::window::Key;
 use std::collections::HashSet;
+use super::{Action, Controls, Keyboard};
 
 pub struct InputHandler {
-    valid_pressed_keys: HashSet<Key>,
-    previously_pressed_keys: HashSet<Key>,
+    controls: Controls,
+    valid_pressed_actions: Vec<Action>,
+    held_actions: HashSet<Action>,
 }
 
 impl InputHandler {
-    pub fn new() -> InputHandler {
+    pub fn new(controls: Controls) -> InputHandler {
         InputHandler {
-            valid_pressed_keys: HashSet::new(),
-            previously_pressed_keys: HashSet::new(),
+            controls,
+            valid_pressed_actions: Vec::new(),
+            held_actions: HashSet::new(),
         }
     }
 
-    pub fn tick(&mut self) {
-        let relevant_keys = [
-            Key::Left,
-            Key::Right,
-            Key::A,
-            Key::S,
-        ];
+    pub fn tick(&mut self, keyboard: &dyn Keyboard) {
+        self.valid_pressed_actions.clear();
 
-        for key in &relevant_keys {
-            let is_key_pressed = key.is_pressed();
+        for (key, action) in self.controls.get_bindings().iter() {
+            let is_key_pressed = keyboard.is_key_pressed(*key);
 
-            if is_key_pressed && !self.previously_pressed_keys.contains(key) {
-                self.valid_pressed_keys.insert(*key);
-            } else {
-                self.valid_pressed_keys.remove(key);
+            if is_key_pressed && !self.held_actions.contains(action) {
+                self.valid_pressed_actions.push(*action);
             }
 
             if is_key_pressed {
-                self.previously_pressed_keys.insert(*key);
+                self.held_actions.insert(*action);
             } else {
-                self.previously_pressed_keys.remove(key);
+                self.held_actions.remove(action);
             }
         }
     }
 
-    pub fn get_pressed_keys(&self) -> impl Iterator<Item = &Key> {
-        self.valid_pressed_keys.iter()
+    /**
+     * Actions whose key went down since the previous tick, in the order of
+     * the key bindings so that simultaneous presses always resolve the same
+     * way.
+     */
+    pub fn get_pressed_actions(&self) -> impl Iterator<Item = &Action> {
+        self.valid_pressed_actions.iter()
+    }
+
+    pub fn is_pressed(&self, action: Action) -> bool {
+        self.valid_pressed_actions.contains(&action)
     }
-}
 
+    /**
+     * Checks if the key of an action is currently down, no matter when it
+     * was pressed.
+     */
+    pub fn is_held(&self, action: Action) -> bool {
+        self.held_actions.contains(&action)
+    }
+
+    pub fn get_controls(&self) -> &Controls {
+        &self.controls
+    }
+}
@@ -0,0 +1,17 @@
+use sfml::window::Key;
+
+/**
+ * Source of keyboard state read by the input handler. The game reads the
+ * real keyboard, but tests and replays can provide their own.
+ */
+pub trait Keyboard {
+    fn is_key_pressed(&self, key: Key) -> bool;
+}
+
+pub struct SfmlKeyboard;
+
+impl Keyboard for SfmlKeyboard {
+    fn is_key_pressed(&self, key: Key) -> bool {
+        key.is_pressed()
+    }
+}
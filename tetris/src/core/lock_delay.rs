@@ -0,0 +1,82 @@
+use super::LockResetPolicy;
+
+// Moves and rotations that can restart the timer before the piece has to
+// reach a new row, as in the guideline's limited "infinity"
+pub const MAX_MOVE_RESETS: usize = 15;
+
+/**
+ * Counts the frames a piece spends on the ground and decides when it locks.
+ */
+pub struct LockDelay {
+    policy: LockResetPolicy,
+    num_frames: u64,
+    elapsed_frames: u64,
+    num_move_resets: usize,
+    lowest_row: isize,
+}
+
+impl LockDelay {
+    pub fn new(policy: LockResetPolicy, num_frames: u64) -> LockDelay {
+        LockDelay {
+            policy,
+            num_frames,
+            elapsed_frames: 0,
+            num_move_resets: 0,
+            lowest_row: 0,
+        }
+    }
+
+    pub fn get_elapsed_frames(&self) -> u64 {
+        self.elapsed_frames
+    }
+
+    pub fn get_num_move_resets(&self) -> usize {
+        self.num_move_resets
+    }
+
+    pub fn on_spawn(&mut self, row: isize) {
+        self.elapsed_frames = 0;
+        self.num_move_resets = 0;
+        self.lowest_row = row;
+    }
+
+    /**
+     * Advances the timer by a frame spent on the ground, returning whether
+     * the piece must lock now.
+     */
+    pub fn tick_grounded(&mut self) -> bool {
+        self.elapsed_frames += 1;
+
+        self.elapsed_frames >= self.num_frames
+    }
+
+    pub fn on_step_down(&mut self, row: isize) {
+        let is_new_lowest_row = row > self.lowest_row;
+        self.lowest_row = self.lowest_row.max(row);
+
+        match self.policy {
+            LockResetPolicy::None => {},
+            LockResetPolicy::StepReset => self.elapsed_frames = 0,
+            LockResetPolicy::MoveReset => {
+                if is_new_lowest_row {
+                    self.elapsed_frames = 0;
+                    self.num_move_resets = 0;
+                }
+            },
+        }
+    }
+
+    /**
+     * Called after a successful move or rotation.
+     */
+    pub fn on_move(&mut self) {
+        if self.policy != LockResetPolicy::MoveReset || self.elapsed_frames == 0 {
+            return;
+        }
+
+        if self.num_move_resets < MAX_MOVE_RESETS {
+            self.elapsed_frames = 0;
+            self.num_move_resets += 1;
+        }
+    }
+}
@@ -0,0 +1,13 @@
+/**
+ * What gives a grounded piece more time before it locks.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LockResetPolicy {
+    // The timer never goes back, moving on the ground only wastes time
+    None,
+    // The timer restarts whenever the piece falls by a row
+    StepReset,
+    // Successful moves and rotations restart the timer, a limited number of
+    // times per row reached
+    MoveReset,
+}
@@ -1,20 +1,41 @@
+mod action;
 mod active_piece;
+mod auto_shift;
+mod controls;
 mod delay;
 mod direction;
 mod game_assets;
 mod game_loop;
+mod game_over_reason;
 mod game_renderer;
+mod handling;
 mod input_handler;
+mod keyboard;
+mod lock_delay;
+mod lock_reset_policy;
 mod model;
+mod phase;
 mod traits;
 mod view;
 
+#[cfg(test)]
+mod tests;
+
+pub use self::action::Action;
 pub use self::active_piece::ActivePiece;
+pub use self::auto_shift::AutoShift;
+pub use self::controls::Controls;
 pub use self::delay::Delay;
 pub use self::direction::Direction;
 pub use self::game_assets::GameAssets;
 pub use self::game_loop::GameLoop;
+pub use self::game_over_reason::GameOverReason;
 pub use self::game_renderer::GameRenderer;
+pub use self::handling::{Handling, HandlingDelay};
 pub use self::input_handler::InputHandler;
+pub use self::keyboard::{Keyboard, SfmlKeyboard};
+pub use self::lock_delay::LockDelay;
+pub use self::lock_reset_policy::LockResetPolicy;
 pub use self::model::Model;
+pub use self::phase::Phase;
 pub use self::view::View;
@@ -1,17 +1,67 @@
-use sfml::window::Key;
+use std::collections::VecDeque;
 use super::super::board::{Block, Board, MaterializationStatus, SimpleBoard};
-use super::super::gravity::{BoardGravityPair, Gravity};
+use super::super::gravity::{BoardGravityPair, ClearReport, Gravity};
+use super::super::gravity::cascade::{CascadeGravity, CascadeGravityPair};
 use super::super::gravity::naive::{NaiveGravity, NaiveGravityPair};
-use super::super::piece::{Piece, PieceColor, PieceKind};
+use super::super::gravity::sticky::{StickyGravity, StickyGravityPair};
+use super::super::helpers;
+use super::super::mode::{FreePlayMode, GameMode, GameModeKind, GameStats, MarathonMode};
+use super::super::piece::{ColorScheme, ColorSchemeKind, Piece, PieceColor, PieceKind};
+use super::super::piece::color_scheme::{GuidelineColorScheme, NesColorScheme};
 use super::super::position::BoardPosition;
-use super::super::rotations::{RotationDirection, RotationSystem};
-use super::super::settings::Settings;
+use super::super::randomizer::{
+    BagRandomizer,
+    NesRandomizer,
+    Randomizer,
+    RandomizerKind,
+    Tgm1Randomizer,
+    Tgm3Randomizer,
+};
+use super::super::rotations::{self, RotationDirection, RotationSystem, RotationSystemError};
+use super::super::scoring::{
+    ChainEvent,
+    GuidelineScoring,
+    LockEvent,
+    NesScoring,
+    Scoring,
+    ScoringKind,
+    Spin,
+};
+use super::super::settings::{self, Settings};
+use super::super::speed::{
+    GravityCurve,
+    GravityCurveKind,
+    GuidelineGravityCurve,
+    InstantGravityCurve,
+    NesGravityCurve,
+    TgmGravityCurve,
+    MAX_CELLS_PER_FRAME,
+};
 use super::traits::Tick;
-use super::{ActivePiece, Delay, Direction, InputHandler};
+use super::{
+    Action,
+    ActivePiece,
+    AutoShift,
+    Direction,
+    GameOverReason,
+    InputHandler,
+    Keyboard,
+    LockDelay,
+    Phase,
+    SfmlKeyboard,
+};
+
+const FALL_PROGRESS_TOLERANCE: f32 = 1e-4;
 
 pub struct ModelSettings {
     pub board_size: (usize, usize),
+    pub buffer_rows: usize,
     pub gravity: Gravity,
+    pub entry_delay_frames: u64,
+    pub line_clear_delay_frames: u64,
+    pub initial_rotation: bool,
+    pub initial_hold: bool,
+    pub all_spin: bool,
 }
 
 pub struct Model {
@@ -19,9 +69,32 @@ pub struct Model {
     active_piece: Option<ActivePiece>,
     rotation_system: Option<RotationSystem>,
     input_handler: InputHandler,
+    auto_shift: AutoShift,
+    frame_duration_nanos: u64,
+    lock_delay: LockDelay,
+    gravity_curve: Box<dyn GravityCurve>,
+    fall_progress: f32,
+    level: usize,
+    keyboard: Box<dyn Keyboard>,
+    randomizer: Box<dyn Randomizer>,
+    color_scheme: Box<dyn ColorScheme>,
+    next_pieces: VecDeque<Piece>,
+    held_piece: Option<Piece>,
+    can_hold: bool,
+    soft_drop_distance: usize,
+    hard_drop_distance: usize,
+    scoring: Box<dyn Scoring>,
+    game_mode: Box<dyn GameMode>,
+    num_cleared_lines: usize,
+    elapsed_nanos: u64,
+    combo: Option<usize>,
+    is_back_to_back_ready: bool,
+    last_lock_event: Option<LockEvent>,
+    last_clear_report: Option<ClearReport>,
     settings: ModelSettings,
+    phase: Phase,
+    game_over_reason: Option<GameOverReason>,
     running: bool,
-    delay: Delay,
 }
 
 /**
@@ -36,6 +109,87 @@ impl Model {
         &self.active_piece
     }
 
+    pub fn get_next_pieces(&self) -> &VecDeque<Piece> {
+        &self.next_pieces
+    }
+
+    pub fn get_held_piece(&self) -> &Option<Piece> {
+        &self.held_piece
+    }
+
+    /**
+     * Color of a waiting piece (queued or held) at the current level. The
+     * color scheme may depend on the level, so pieces only get their color
+     * for good when they spawn.
+     */
+    pub fn get_piece_color(&self, piece: &Piece) -> PieceColor {
+        self.color_scheme.get_color(piece.get_kind(), self.level)
+    }
+
+    /**
+     * Rows the current piece (or the last one, until the next spawns) fell
+     * while soft dropping.
+     */
+    pub fn get_soft_drop_distance(&self) -> usize {
+        self.soft_drop_distance
+    }
+
+    /**
+     * Rows the current piece (or the last one, until the next spawns) fell
+     * when hard dropped.
+     */
+    pub fn get_hard_drop_distance(&self) -> usize {
+        self.hard_drop_distance
+    }
+
+    pub fn get_score(&self) -> u64 {
+        self.scoring.get_score()
+    }
+
+    pub fn get_num_cleared_lines(&self) -> usize {
+        self.num_cleared_lines
+    }
+
+    pub fn get_last_lock_event(&self) -> &Option<LockEvent> {
+        &self.last_lock_event
+    }
+
+    /**
+     * Rows cleared in each step of the last clear, including chain steps.
+     */
+    pub fn get_last_clear_report(&self) -> &Option<ClearReport> {
+        &self.last_clear_report
+    }
+
+    pub fn get_game_over_reason(&self) -> &Option<GameOverReason> {
+        &self.game_over_reason
+    }
+
+    pub fn get_phase(&self) -> &Phase {
+        &self.phase
+    }
+
+    pub fn get_level(&self) -> usize {
+        self.level
+    }
+
+    /**
+     * Score, lines, level and playing time so far, or final once the game
+     * is over.
+     */
+    pub fn get_stats(&self) -> GameStats {
+        GameStats {
+            score: self.get_score(),
+            num_cleared_lines: self.num_cleared_lines,
+            level: self.level,
+            elapsed_nanos: self.elapsed_nanos,
+        }
+    }
+
+    pub fn get_lock_delay(&self) -> &LockDelay {
+        &self.lock_delay
+    }
+
     pub fn get_rotation_system(&self) -> &RotationSystem {
         if let Some(active_piece) = &self.active_piece {
             active_piece.get_rotation_system()
@@ -48,6 +202,14 @@ impl Model {
         self.get_board().get_num_rows()
     }
 
+    pub fn get_board_num_visible_rows(&self) -> usize {
+        self.get_board().get_num_visible_rows()
+    }
+
+    pub fn get_board_num_hidden_rows(&self) -> usize {
+        self.get_board().get_num_hidden_rows()
+    }
+
     pub fn get_board_num_columns(&self) -> usize {
         self.get_board().get_num_columns()
     }
@@ -63,70 +225,148 @@ impl Model {
 }
 
 impl Tick for Model {
+    /**
+     * Returns true once the game is over, which stops the game loop.
+     */
     fn tick(&mut self, elapsed_time_nanos: u64) -> bool {
-        if !self.running {
-            return false;
+        if self.running {
+            self.tick_frame(elapsed_time_nanos);
         }
 
-        if !self.has_active_piece() {
-            self.spawn_piece();
-            return false;
-        }
+        self.game_over_reason.is_some()
+    }
+}
 
-        self.handle_input();
+/**
+ * tick implementation
+ */
+impl Model {
+    fn tick_frame(&mut self, elapsed_time_nanos: u64) {
+        self.frame_duration_nanos = elapsed_time_nanos;
+        self.elapsed_nanos += elapsed_time_nanos;
+        self.input_handler.tick(self.keyboard.as_ref());
+        // DAS keeps charging during the delays between pieces
+        let shift = self.auto_shift.tick(&self.input_handler, elapsed_time_nanos);
 
-        self.delay.tick();
-        if self.delay.block_for_frames(15) {
-            return false;
+        match self.phase {
+            Phase::Falling => {},
+            Phase::LineClear { remaining_frames } => {
+                self.tick_line_clear(remaining_frames);
+                return;
+            },
+            Phase::Entry { remaining_frames } => {
+                self.tick_entry(remaining_frames);
+                return;
+            },
         }
 
-        let can_move_down = self.active_piece
-            .as_mut()
-            .unwrap()
-            .can_move_towards(
-                &Direction::Down,
-                self.board_gravity_pair.board()
-            );
-
-        if can_move_down {
-            self.get_active_piece_mut().move_towards(&Direction::Down);
-            return false;
+        if let Some((direction, num_cells)) = shift {
+            self.shift(direction, num_cells);
         }
 
-        let mut active_piece = self.active_piece.take().unwrap();
-
-        match active_piece.materialize_at(self.board_gravity_pair.board_mut()) {
-            MaterializationStatus::Success => {},
-            MaterializationStatus::Failure => {
-                self.running = false;
-                return false
-            }
+        // The next piece may already be in play after a hard drop, but it
+        // only starts falling on the next frame
+        if self.handle_input() || !self.has_active_piece() {
+            return;
         }
 
-        active_piece.transfer_rotation_system(&mut self.rotation_system);
+        if self.is_active_piece_grounded() {
+            if self.lock_delay.tick_grounded() {
+                self.lock_active_piece();
+            }
 
-        self.clear_filled_rows();
+            return;
+        }
 
-        false
+        self.fall();
     }
 }
 
 impl Model {
-    pub fn new(settings: Settings) -> Model {
+    /**
+     * Creates a model, rejecting settings whose rotation system is invalid.
+     */
+    pub fn new(settings: Settings) -> Result<Model, Vec<RotationSystemError>> {
+        rotations::validate(&settings.rotation_system)?;
+
         let model_settings = ModelSettings {
             board_size: settings.board_size,
+            buffer_rows: settings.buffer_rows,
             gravity: settings.gravity,
+            entry_delay_frames: settings.entry_delay_frames,
+            line_clear_delay_frames: settings.line_clear_delay_frames,
+            initial_rotation: settings.initial_rotation,
+            initial_hold: settings.initial_hold,
+            all_spin: settings.all_spin,
         };
 
-        Model {
-            board_gravity_pair: get_boxed_gravity(&model_settings.gravity, &model_settings.board_size),
+        let num_next_pieces = settings.num_next_pieces.clamp(
+            settings::MIN_NUM_NEXT_PIECES,
+            settings::MAX_NUM_NEXT_PIECES,
+        );
+
+        let mut model = Model {
+            board_gravity_pair: get_boxed_gravity(
+                &model_settings.gravity,
+                &model_settings.board_size,
+                model_settings.buffer_rows,
+            ),
             active_piece: None,
             rotation_system: Some(settings.rotation_system),
-            input_handler: InputHandler::new(),
+            input_handler: InputHandler::new(settings.controls),
+            auto_shift: AutoShift::new(settings.handling),
+            frame_duration_nanos: 0,
+            lock_delay: LockDelay::new(settings.lock_reset_policy, settings.lock_delay_frames),
+            gravity_curve: get_boxed_gravity_curve(&settings.gravity_curve),
+            fall_progress: 0.,
+            level: settings.level,
+            keyboard: Box::new(SfmlKeyboard),
+            randomizer: get_boxed_randomizer(&settings.randomizer, settings.seed),
+            color_scheme: get_boxed_color_scheme(&settings.color_scheme),
+            next_pieces: VecDeque::with_capacity(num_next_pieces),
+            held_piece: None,
+            can_hold: true,
+            soft_drop_distance: 0,
+            hard_drop_distance: 0,
+            scoring: get_boxed_scoring(&settings.scoring),
+            game_mode: get_boxed_game_mode(
+                &settings.game_mode,
+                settings.level,
+                settings.marathon_end_level,
+            ),
+            num_cleared_lines: 0,
+            elapsed_nanos: 0,
+            combo: None,
+            is_back_to_back_ready: false,
+            last_lock_event: None,
+            last_clear_report: None,
             settings: model_settings,
+            phase: Phase::Entry { remaining_frames: 0 },
+            game_over_reason: None,
             running: true, // TODO: change to false later
-            delay: Delay::new(),
+        };
+
+        for _ in 0..num_next_pieces {
+            let piece = model.make_next_piece();
+            model.next_pieces.push_back(piece);
         }
+
+        Ok(model)
+    }
+
+    /**
+     * Replaces the source of keyboard state, e.g. to feed recorded input.
+     */
+    pub fn set_keyboard(&mut self, keyboard: Box<dyn Keyboard>) {
+        self.keyboard = keyboard;
+    }
+
+    /**
+     * Replaces the rules deciding the level and the end of the game.
+     */
+    pub fn set_game_mode(&mut self, game_mode: Box<dyn GameMode>) {
+        self.level = game_mode.get_level(self.num_cleared_lines);
+        self.game_mode = game_mode;
     }
 
     pub fn change_gravity(&mut self, gravity: Gravity) {
@@ -134,7 +374,8 @@ impl Model {
 
         self.board_gravity_pair = get_boxed_gravity(
             &self.settings.gravity,
-            &self.settings.board_size
+            &self.settings.board_size,
+            self.settings.buffer_rows,
         );
     }
 }
@@ -152,14 +393,69 @@ impl Model {
  * spawn_piece implementation + helpers
  */
 impl Model {
+    /**
+     * Spawns the first piece of the queue and refills it from the randomizer.
+     */
     fn spawn_piece(&mut self) {
-        let piece = random_piece();
+        let piece = self.take_next_piece();
+        self.spawn(piece);
+    }
+
+    fn take_next_piece(&mut self) -> Piece {
+        let next_piece = self.make_next_piece();
+        self.next_pieces.push_back(next_piece);
+
+        self.next_pieces.pop_front().unwrap()
+    }
+
+    fn make_next_piece(&mut self) -> Piece {
+        let kind = self.randomizer.next_piece();
+        // Replaced when the piece spawns, in case the level changed
+        let color = self.color_scheme.get_color(&kind, self.level);
+
+        Piece::new(kind, color, 0)
+    }
+
+    fn spawn(&mut self, piece: Piece) {
+        self.spawn_rotated(piece, None);
+    }
+
+    /**
+     * Spawns a piece, turned beforehand in the given direction if it fits
+     * that way. Otherwise it keeps its spawn orientation.
+     */
+    fn spawn_rotated(&mut self, mut piece: Piece, rotation: Option<RotationDirection>) {
+        piece.set_color(self.get_piece_color(&piece));
+
         let position = self.get_centralized_position_for(&piece);
+        self.soft_drop_distance = 0;
+        self.hard_drop_distance = 0;
+        self.auto_shift.on_spawn(self.frame_duration_nanos);
+        self.fall_progress = 0.;
 
         // self.active_piece = Some(ActivePiece { piece, position, rotation_system: &self.settings.rotation_system });
-        self.active_piece = Some(
-            ActivePiece::new(piece, position, self.rotation_system.take().unwrap())
-        );
+        let mut active_piece = ActivePiece::new(piece, position, self.rotation_system.take().unwrap());
+
+        if let Some(direction) = rotation {
+            active_piece.try_rotate_without_kicks(direction, self.get_board());
+        }
+
+        if !active_piece.fits(self.get_board()) {
+            active_piece.transfer_rotation_system(&mut self.rotation_system);
+            self.game_over(GameOverReason::BlockOut);
+            return;
+        }
+
+        if self.has_instant_gravity() {
+            active_piece.enable_instant_gravity(self.board_gravity_pair.board());
+        }
+
+        self.lock_delay.on_spawn(active_piece.get_position().row);
+        self.active_piece = Some(active_piece);
+    }
+
+    fn has_instant_gravity(&self) -> bool {
+        self.gravity_curve.get_cells_per_frame(self.level) >= MAX_CELLS_PER_FRAME
     }
 
     fn get_centralized_position_for(&self, piece: &Piece) -> BoardPosition {
@@ -167,11 +463,32 @@ impl Model {
         let board_num_columns = self.get_board_num_columns();
 
         BoardPosition::new(
-            0,
+            self.get_spawn_row_for(piece),
             ((board_num_columns - grid_num_columns) / 2) as isize,
         )
     }
 
+    /**
+     * Pieces spawn with their lowest blocks in the last hidden row, right
+     * above the visible area. Boards without a buffer spawn at the top.
+     */
+    fn get_spawn_row_for(&self, piece: &Piece) -> isize {
+        let num_hidden_rows = self.get_board().get_num_hidden_rows() as isize;
+
+        if num_hidden_rows == 0 {
+            return 0;
+        }
+
+        let origin = BoardPosition::new(0, 0);
+        let block_rows: Vec<_> = helpers::get_piece_iterator(piece, &origin, self.get_rotation_system())
+            .map(|block| block.row)
+            .collect();
+        let min_block_row = block_rows.iter().cloned().min().unwrap_or(0);
+        let max_block_row = block_rows.iter().cloned().max().unwrap_or(0);
+
+        (num_hidden_rows - 1 - max_block_row).max(-min_block_row)
+    }
+
     fn get_grid_num_columns(&self, piece: &Piece) -> usize {
         let grid = piece.get_grid(self.get_rotation_system());
         let grid_size = grid.0.len();
@@ -184,49 +501,99 @@ impl Model {
  * handle_input implementation + helpers
  */
 impl Model {
-    fn handle_input(&mut self) {
-        self.input_handler.tick();
-
+    /**
+     * Returns true if a hard drop locked the active piece.
+     */
+    fn handle_input(&mut self) -> bool {
         // TODO: find a better solution to this borrow checker issue
-        let pressed_keys: Vec<Key> = self.input_handler.get_pressed_keys()
+        let pressed_actions: Vec<Action> = self.input_handler.get_pressed_actions()
             .cloned()
             .collect();
 
-        pressed_keys.iter().for_each(|key| {
-            match key {
-                Key::Left | Key::Right => {
-                    let direction = match key {
-                        Key::Left => Direction::Left,
-                        Key::Right => Direction::Right,
-                        _ => unreachable!(),
-                    };
+        for action in pressed_actions {
+            // Holding into a blocked spawn ends the game
+            if !self.has_active_piece() {
+                return false;
+            }
 
-                    self.active_piece
-                        .as_mut()
-                        .unwrap()
-                        .try_move_towards(
-                            direction,
-                            self.board_gravity_pair.board(),
-                        );
-                },
-                Key::A | Key::S => {
-                    let direction = match key {
-                        Key::A => RotationDirection::Counterclockwise,
-                        Key::S => RotationDirection::Clockwise,
+            match action {
+                Action::RotateCounterclockwise | Action::RotateClockwise | Action::RotateHalf => {
+                    let direction = match action {
+                        Action::RotateCounterclockwise => RotationDirection::Counterclockwise,
+                        Action::RotateClockwise => RotationDirection::Clockwise,
+                        Action::RotateHalf => RotationDirection::Half,
                         _ => unreachable!(),
                     };
 
-                    self.active_piece
+                    let previous_row = self.active_piece.as_ref().unwrap().get_position().row;
+                    let has_rotated = self.active_piece
                         .as_mut()
                         .unwrap()
                         .try_rotate(
                             direction,
                             self.board_gravity_pair.board(),
                         );
+
+                    if has_rotated {
+                        self.on_active_piece_moved(previous_row);
+                    }
                 },
-                _ => {},
+                Action::Hold => self.hold(),
+                // Later actions must not reach the next piece
+                Action::HardDrop => {
+                    self.hard_drop();
+                    return true;
+                },
+                // Handled by the auto shift and the gravity step
+                Action::MoveLeft | Action::MoveRight | Action::SoftDrop => {},
             }
-        });
+        }
+
+        false
+    }
+
+    /**
+     * Moves the piece sideways by up to the given number of cells, stopping
+     * early if it gets blocked.
+     */
+    fn shift(&mut self, direction: Direction, num_cells: usize) {
+        let active_piece = self.active_piece.as_mut().unwrap();
+        let board = self.board_gravity_pair.board();
+        let previous_row = active_piece.get_position().row;
+        let mut has_moved = false;
+
+        for _ in 0..num_cells {
+            if !active_piece.try_move_towards(direction, board) {
+                break;
+            }
+
+            has_moved = true;
+        }
+
+        if has_moved {
+            self.on_active_piece_moved(previous_row);
+        }
+    }
+
+    /**
+     * Updates the lock delay after a successful move or rotation, which
+     * may have made the piece fall under 20G.
+     */
+    fn on_active_piece_moved(&mut self, previous_row: isize) {
+        let row = self.active_piece.as_ref().unwrap().get_position().row;
+
+        if row > previous_row {
+            self.lock_delay.on_step_down(row);
+        }
+
+        self.lock_delay.on_move();
+    }
+
+    fn is_active_piece_grounded(&self) -> bool {
+        !self.active_piece
+            .as_ref()
+            .unwrap()
+            .can_move_towards(&Direction::Down, self.get_board())
     }
 
     fn get_active_piece_mut(&mut self) -> &mut ActivePiece {
@@ -234,35 +601,455 @@ impl Model {
     }
 }
 
+/**
+ * hold implementation
+ */
+impl Model {
+    /**
+     * Swaps the active piece with the held one, or with the next piece if
+     * nothing is held yet. Holding is disabled until the next piece locks.
+     */
+    fn hold(&mut self) {
+        if !self.can_hold {
+            return;
+        }
+
+        let (mut piece, rotation_system) = self.active_piece.take().unwrap().into_parts();
+        self.rotation_system = Some(rotation_system);
+        piece.reset_rotation();
+
+        match self.held_piece.replace(piece) {
+            Some(held_piece) => self.spawn(held_piece),
+            None => self.spawn_piece(),
+        }
+
+        self.can_hold = false;
+    }
+}
+
+/**
+ * fall implementation + helpers
+ */
+impl Model {
+    /**
+     * Moves the piece down by the whole cells gravity accumulated so far,
+     * stopping early if it lands.
+     */
+    fn fall(&mut self) {
+        let is_soft_dropping = self.input_handler.is_held(Action::SoftDrop);
+
+        self.fall_progress += self.get_cells_per_frame(is_soft_dropping);
+
+        // Summing fractions such as 1/48 falls slightly short of a whole
+        // cell, which would delay the step by a frame
+        let num_cells = (self.fall_progress + FALL_PROGRESS_TOLERANCE).floor();
+        self.fall_progress = (self.fall_progress - num_cells).max(0.);
+
+        let num_cells = if self.active_piece.as_ref().unwrap().has_instant_gravity() {
+            usize::MAX
+        } else {
+            num_cells as usize
+        };
+
+        for _ in 0..num_cells {
+            if self.is_active_piece_grounded() {
+                self.fall_progress = 0.;
+                break;
+            }
+
+            self.get_active_piece_mut().move_towards(&Direction::Down);
+
+            if is_soft_dropping {
+                self.soft_drop_distance += 1;
+            }
+
+            let row = self.active_piece.as_ref().unwrap().get_position().row;
+            self.lock_delay.on_step_down(row);
+        }
+    }
+
+    fn get_cells_per_frame(&self, is_soft_dropping: bool) -> f32 {
+        let cells_per_frame = self.gravity_curve.get_cells_per_frame(self.level);
+
+        if is_soft_dropping {
+            cells_per_frame.max(1. / settings::SOFT_DROP_DELAY_FRAMES as f32)
+        } else {
+            cells_per_frame
+        }
+    }
+}
+
+/**
+ * hard_drop implementation
+ */
+impl Model {
+    fn hard_drop(&mut self) {
+        self.hard_drop_distance = self.active_piece
+            .as_mut()
+            .unwrap()
+            .hard_drop(self.board_gravity_pair.board());
+
+        self.lock_active_piece();
+    }
+}
+
+/**
+ * lock_active_piece implementation
+ */
+impl Model {
+    fn lock_active_piece(&mut self) {
+        let mut active_piece = self.active_piece.take().unwrap();
+        let piece_kind = *active_piece.get_piece().get_kind();
+        let spin = active_piece.detect_spin(self.get_board(), self.settings.all_spin);
+        let first_visible_row = self.get_first_visible_row();
+        let is_above_visible_area = active_piece.get_block_iterator()
+            .all(|position| position.row < first_visible_row);
+
+        match active_piece.materialize_at(self.board_gravity_pair.board_mut()) {
+            MaterializationStatus::Success => {},
+            // The active piece never overlaps the stack, so this is a spawn
+            // that went unnoticed
+            MaterializationStatus::Failure => {
+                active_piece.transfer_rotation_system(&mut self.rotation_system);
+                self.game_over(GameOverReason::BlockOut);
+                return;
+            }
+        }
+
+        active_piece.transfer_rotation_system(&mut self.rotation_system);
+
+        if is_above_visible_area {
+            self.game_over(GameOverReason::LockOut);
+            return;
+        }
+
+        self.can_hold = true;
+
+        let num_filled_rows = self.get_board().get_filled_rows().len();
+        self.score_lock(piece_kind, spin, num_filled_rows);
+
+        let has_filled_rows = num_filled_rows > 0;
+        let line_clear_delay_frames = self.settings.line_clear_delay_frames;
+
+        if has_filled_rows && line_clear_delay_frames > 0 {
+            self.phase = Phase::LineClear { remaining_frames: line_clear_delay_frames };
+        } else {
+            self.clear_filled_rows();
+        }
+    }
+}
+
+/**
+ * Garbage and game over
+ */
+impl Model {
+    /**
+     * Adds garbage rows with a hole in the given column at the bottom of the
+     * board, lifting the active piece if it gets in the way. The game is
+     * topped out if blocks get pushed past the top.
+     */
+    pub fn add_garbage(&mut self, num_rows: usize, hole_column: usize) {
+        if !self.running {
+            return;
+        }
+
+        if !self.board_gravity_pair.board_mut().push_garbage_rows(num_rows, hole_column) {
+            self.game_over(GameOverReason::TopOut);
+            return;
+        }
+
+        let board = self.board_gravity_pair.board();
+        let has_room = self.active_piece
+            .as_mut()
+            .map(|active_piece| lift_until_it_fits(active_piece, board))
+            .unwrap_or(true);
+
+        if !has_room {
+            self.game_over(GameOverReason::TopOut);
+        }
+    }
+
+    fn game_over(&mut self, reason: GameOverReason) {
+        self.running = false;
+        self.game_over_reason = Some(reason);
+    }
+
+    fn get_first_visible_row(&self) -> isize {
+        self.get_board().get_num_hidden_rows() as isize
+    }
+}
+
+/**
+ * score_lock implementation + helpers
+ */
+impl Model {
+    fn score_lock(&mut self, piece_kind: PieceKind, spin: Spin, num_cleared_rows: usize) {
+        let mut event = LockEvent {
+            piece_kind,
+            level: self.level,
+            num_cleared_rows,
+            soft_drop_distance: self.soft_drop_distance,
+            hard_drop_distance: self.hard_drop_distance,
+            spin,
+            combo: self.update_combo(num_cleared_rows),
+            is_back_to_back: false,
+        };
+
+        if event.is_difficult_clear() {
+            event.is_back_to_back = self.is_back_to_back_ready;
+            self.is_back_to_back_ready = true;
+        } else if num_cleared_rows > 0 {
+            self.is_back_to_back_ready = false;
+        }
+
+        self.scoring.on_lock(&event);
+        self.last_lock_event = Some(event);
+    }
+
+    /**
+     * Returns the number of line-clearing locks right before this one,
+     * breaking the combo if this lock clears nothing.
+     */
+    fn update_combo(&mut self, num_cleared_rows: usize) -> usize {
+        if num_cleared_rows == 0 {
+            self.combo = None;
+            return 0;
+        }
+
+        let combo = self.combo.map_or(0, |combo| combo + 1);
+        self.combo = Some(combo);
+
+        combo
+    }
+}
+
+/**
+ * Delays between pieces
+ */
+impl Model {
+    /**
+     * Waits for the line clear delay to run out, then removes the filled
+     * rows and moves on to the entry delay.
+     */
+    fn tick_line_clear(&mut self, remaining_frames: u64) {
+        if remaining_frames > 1 {
+            self.phase = Phase::LineClear { remaining_frames: remaining_frames - 1 };
+            return;
+        }
+
+        self.clear_filled_rows();
+    }
+
+    /**
+     * Starts the entry delay, or spawns the next piece right away when there
+     * is none.
+     */
+    fn start_entry_delay(&mut self) {
+        match self.settings.entry_delay_frames {
+            0 => self.end_entry_delay(),
+            entry_delay_frames => {
+                self.phase = Phase::Entry { remaining_frames: entry_delay_frames };
+            },
+        }
+    }
+
+    /**
+     * Waits for the entry delay to run out, then spawns the next piece.
+     */
+    fn tick_entry(&mut self, remaining_frames: u64) {
+        if remaining_frames > 1 {
+            self.phase = Phase::Entry { remaining_frames: remaining_frames - 1 };
+            return;
+        }
+
+        self.end_entry_delay();
+    }
+
+    fn end_entry_delay(&mut self) {
+        self.spawn_with_initial_actions();
+        self.phase = Phase::Falling;
+    }
+}
+
+/**
+ * Initial Hold System (IHS) and Initial Rotation System (IRS)
+ */
+impl Model {
+    /**
+     * Spawns the next piece, applying the hold and rotation whose keys are
+     * down so that they can be buffered during the delays. Both happen
+     * before the spawn is checked for a block out and before 20G drops the
+     * piece.
+     */
+    fn spawn_with_initial_actions(&mut self) {
+        let mut piece = self.take_next_piece();
+        let is_hold_held = self.input_handler.is_held(Action::Hold);
+
+        if self.settings.initial_hold && self.can_hold && is_hold_held {
+            piece = match self.held_piece.replace(piece) {
+                Some(held_piece) => held_piece,
+                None => self.take_next_piece(),
+            };
+            self.can_hold = false;
+        }
+
+        let rotation = if self.settings.initial_rotation {
+            self.get_initial_rotation()
+        } else {
+            None
+        };
+
+        self.spawn_rotated(piece, rotation);
+    }
+
+    /**
+     * Returns the direction of the rotation key that is down, if any.
+     */
+    fn get_initial_rotation(&self) -> Option<RotationDirection> {
+        let rotations = [
+            (Action::RotateClockwise, RotationDirection::Clockwise),
+            (Action::RotateCounterclockwise, RotationDirection::Counterclockwise),
+            (Action::RotateHalf, RotationDirection::Half),
+        ];
+
+        rotations.iter()
+            .find(|(action, _)| self.input_handler.is_held(*action))
+            .map(|(_, direction)| *direction)
+    }
+}
+
 /**
  * clear_filled_rows implementation
  */
 impl Model {
+    /**
+     * Clears the filled rows, along with the rows completed by blocks
+     * falling afterwards, then moves on to the entry delay unless that
+     * ends the game.
+     */
     fn clear_filled_rows(&mut self) {
         let filled_rows = self.get_board().get_filled_rows();
+        let report = self.board_gravity_pair.clear_rows(&filled_rows);
 
-        self.board_gravity_pair.clear_rows(&filled_rows);
+        if report.get_num_steps() > 0 {
+            self.on_rows_cleared(&report);
+            self.last_clear_report = Some(report);
+        }
+
+        if self.game_mode.is_goal_reached(self.num_cleared_lines) {
+            self.game_over(GameOverReason::GoalReached);
+            return;
+        }
+
+        self.start_entry_delay();
+    }
+
+    /**
+     * Counts every cleared row and scores the chain steps. The first step
+     * is already paid by the lock event.
+     */
+    fn on_rows_cleared(&mut self, report: &ClearReport) {
+        for (chain, rows) in report.get_steps().iter().enumerate().skip(1) {
+            self.scoring.on_chain_clear(&ChainEvent {
+                level: self.level,
+                chain,
+                num_cleared_rows: rows.len(),
+            });
+        }
+
+        self.num_cleared_lines += report.get_num_cleared_rows();
+        self.level = self.game_mode.get_level(self.num_cleared_lines);
     }
 }
 
+/**
+ * Lifts a piece until it no longer overlaps the stack. Returns false if it
+ * reaches the top of the board first.
+ */
+fn lift_until_it_fits(active_piece: &mut ActivePiece, board: &dyn Board) -> bool {
+    while !active_piece.fits(board) {
+        if active_piece.get_block_iterator().any(|position| position.row <= 0) {
+            return false;
+        }
+
+        active_piece.lift();
+    }
+
+    true
+}
+
 fn get_boxed_gravity(
     gravity: &Gravity,
     board_size: &(usize, usize),
+    buffer_rows: usize,
 ) -> Box<dyn BoardGravityPair> {
+    let (num_columns, num_visible_rows) = *board_size;
+    let board = SimpleBoard::with_hidden_rows(num_columns, num_visible_rows, buffer_rows);
+
     match gravity {
         Gravity::Naive => {
-            let (num_columns, num_rows) = *board_size;
-            let board = SimpleBoard::new(num_columns, num_rows);
             let gravity_instance = NaiveGravity::new();
 
             Box::new(
                 NaiveGravityPair::new(board, gravity_instance)
             )
-        }
+        },
+        Gravity::Sticky => {
+            let gravity_instance = StickyGravity::new();
+
+            Box::new(
+                StickyGravityPair::new(board, gravity_instance)
+            )
+        },
+        Gravity::Cascade => {
+            let gravity_instance = CascadeGravity::new();
+
+            Box::new(
+                CascadeGravityPair::new(board, gravity_instance)
+            )
+        },
+    }
+}
+
+fn get_boxed_randomizer(kind: &RandomizerKind, seed: u64) -> Box<dyn Randomizer> {
+    match kind {
+        RandomizerKind::Bag => Box::new(BagRandomizer::new(seed)),
+        RandomizerKind::Nes => Box::new(NesRandomizer::new(seed)),
+        RandomizerKind::Tgm1 => Box::new(Tgm1Randomizer::new(seed)),
+        RandomizerKind::Tgm3 => Box::new(Tgm3Randomizer::new(seed)),
+    }
+}
+
+fn get_boxed_color_scheme(kind: &ColorSchemeKind) -> Box<dyn ColorScheme> {
+    match kind {
+        ColorSchemeKind::Guideline => Box::new(GuidelineColorScheme),
+        ColorSchemeKind::Nes => Box::new(NesColorScheme),
+    }
+}
+
+fn get_boxed_gravity_curve(kind: &GravityCurveKind) -> Box<dyn GravityCurve> {
+    match kind {
+        GravityCurveKind::Nes => Box::new(NesGravityCurve),
+        GravityCurveKind::Guideline => Box::new(GuidelineGravityCurve),
+        GravityCurveKind::Tgm => Box::new(TgmGravityCurve),
+        GravityCurveKind::Instant => Box::new(InstantGravityCurve),
     }
 }
 
-fn random_piece() -> Piece {
-    // TODO
-    Piece::new(PieceKind::L, PieceColor::Red, 0)
+fn get_boxed_scoring(kind: &ScoringKind) -> Box<dyn Scoring> {
+    match kind {
+        ScoringKind::Nes => Box::new(NesScoring::new()),
+        ScoringKind::Guideline => Box::new(GuidelineScoring::new()),
+    }
+}
+
+fn get_boxed_game_mode(
+    kind: &GameModeKind,
+    level: usize,
+    marathon_end_level: Option<usize>,
+) -> Box<dyn GameMode> {
+    match kind {
+        GameModeKind::FreePlay => Box::new(FreePlayMode::new(level)),
+        GameModeKind::Marathon => Box::new(MarathonMode::new(level, marathon_end_level)),
+    }
 }
@@ -0,0 +1,12 @@
+/**
+ * Where the model stands between two pieces.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    // The active piece is in play
+    Falling,
+    // Filled rows stay on the board for a while before they are cleared
+    LineClear { remaining_frames: u64 },
+    // Entry delay (ARE): nothing is in play until the next piece spawns
+    Entry { remaining_frames: u64 },
+}
@@ -0,0 +1,1513 @@
+use sfml::window::Key;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+use super::super::board::SimpleBoard;
+use super::super::gravity::Gravity;
+use super::super::mode::{GameMode, GameModeKind};
+use super::super::piece::{ColorScheme, ColorSchemeKind, Piece, PieceColor, PieceKind};
+use super::super::piece::color_scheme::NesColorScheme;
+use super::super::position::BoardPosition;
+use super::super::rotations::{self, RotationDirection};
+use super::super::settings::{self, Settings};
+use super::super::scoring::Spin;
+use super::super::speed::GravityCurveKind;
+use super::traits::Tick;
+use super::{ActivePiece, Direction, GameOverReason, HandlingDelay, Keyboard, LockResetPolicy, Model, Phase};
+
+// Slightly above 1/60 of a second, so that millisecond delays are reached on
+// the expected frame
+const FRAME_DURATION_NANOS: u64 = 16_666_667;
+
+struct FakeKeyboard {
+    pressed_keys: Rc<RefCell<HashSet<Key>>>,
+}
+
+impl Keyboard for FakeKeyboard {
+    fn is_key_pressed(&self, key: Key) -> bool {
+        self.pressed_keys.borrow().contains(&key)
+    }
+}
+
+struct TestGame {
+    model: Model,
+    pressed_keys: Rc<RefCell<HashSet<Key>>>,
+}
+
+impl TestGame {
+    fn new() -> TestGame {
+        TestGame::with_settings(make_test_settings())
+    }
+
+    fn with_settings(settings: Settings) -> TestGame {
+        let pressed_keys = Rc::new(RefCell::new(HashSet::new()));
+        let mut model = Model::new(settings).unwrap();
+
+        model.set_keyboard(Box::new(FakeKeyboard {
+            pressed_keys: pressed_keys.clone(),
+        }));
+
+        TestGame { model, pressed_keys }
+    }
+
+    fn tick(&mut self) {
+        self.model.tick(FRAME_DURATION_NANOS);
+    }
+
+    fn press(&mut self, key: Key) {
+        self.pressed_keys.borrow_mut().insert(key);
+    }
+
+    fn release(&mut self, key: Key) {
+        self.pressed_keys.borrow_mut().remove(&key);
+    }
+
+    /**
+     * Presses a key for a single frame.
+     */
+    fn tap(&mut self, key: Key) {
+        self.press(key);
+        self.tick();
+        self.release(key);
+        self.tick();
+    }
+
+    fn tick_frames(&mut self, num_frames: usize) {
+        for _ in 0..num_frames {
+            self.tick();
+        }
+    }
+
+    fn is_grounded(&self) -> bool {
+        self.model.get_lock_delay().get_elapsed_frames() > 0
+    }
+
+    /**
+     * Soft drops the active piece until it touches the ground.
+     */
+    fn soft_drop_to_ground(&mut self) {
+        self.press(Key::Down);
+
+        while !self.is_grounded() {
+            self.tick();
+        }
+
+        self.release(Key::Down);
+    }
+
+    fn active_column(&self) -> isize {
+        self.model.get_active_piece().as_ref().unwrap().get_position().column
+    }
+
+    fn active_lowest_row(&self) -> isize {
+        self.model.get_active_piece()
+            .as_ref()
+            .unwrap()
+            .get_block_iterator()
+            .map(|position| position.row)
+            .max()
+            .unwrap()
+    }
+
+    fn count_blocks(&self) -> usize {
+        let mut num_blocks = 0;
+
+        self.model.for_each_row(&mut |row| {
+            num_blocks += row.iter().filter(|tile| tile.is_some()).count();
+        });
+
+        num_blocks
+    }
+
+    /**
+     * Returns which cells of the board are filled, row by row.
+     */
+    fn get_tiles(&self) -> Vec<Vec<bool>> {
+        let mut tiles = Vec::new();
+
+        self.model.for_each_row(&mut |row| {
+            tiles.push(row.iter().map(|tile| tile.is_some()).collect());
+        });
+
+        tiles
+    }
+
+    fn active_kind(&self) -> PieceKind {
+        *self.model.get_active_piece().as_ref().unwrap().get_piece().get_kind()
+    }
+
+    fn held_kind(&self) -> Option<PieceKind> {
+        self.model.get_held_piece().as_ref().map(|piece| *piece.get_kind())
+    }
+}
+
+fn make_test_settings() -> Settings {
+    let mut settings = settings::make_default_settings();
+    settings.seed = 2019;
+    settings.handling.das = HandlingDelay::Frames(10.);
+    settings.handling.arr = HandlingDelay::Frames(2.);
+    settings.handling.preserve_das_charge = true;
+    // Leaves a frame without a piece after every lock, so that tests can
+    // tell when it happens
+    settings.entry_delay_frames = 1;
+
+    settings
+}
+
+#[test]
+fn hold_stores_the_active_piece_and_spawns_the_next_one() {
+    let mut game = TestGame::new();
+    game.tick();
+
+    let first_kind = game.active_kind();
+    game.tap(Key::LShift);
+
+    assert_eq!(game.held_kind(), Some(first_kind));
+    assert!(game.model.get_active_piece().is_some());
+}
+
+#[test]
+fn hold_is_disabled_until_the_piece_locks() {
+    let mut game = TestGame::new();
+    game.tick();
+
+    let first_kind = game.active_kind();
+    game.tap(Key::LShift);
+    let second_kind = game.active_kind();
+    game.tap(Key::LShift);
+
+    assert_eq!(game.held_kind(), Some(first_kind));
+    assert_eq!(game.active_kind(), second_kind);
+}
+
+#[test]
+fn hold_swaps_with_the_held_piece_after_a_lock() {
+    let mut game = TestGame::new();
+    game.tick();
+
+    let first_kind = game.active_kind();
+    game.tap(Key::LShift);
+
+    // Lets the second piece fall and lock, then spawns the third one
+    while game.model.get_active_piece().is_some() {
+        game.tick();
+    }
+
+    game.tick();
+    let third_kind = game.active_kind();
+    game.tap(Key::LShift);
+
+    assert_eq!(game.active_kind(), first_kind);
+    assert_eq!(game.held_kind(), Some(third_kind));
+}
+
+#[test]
+fn held_piece_goes_back_to_its_spawn_orientation() {
+    let mut game = TestGame::new();
+    game.tick();
+    game.tap(Key::S);
+
+    game.tap(Key::LShift);
+
+    let held_piece = game.model.get_held_piece().as_ref().unwrap();
+    assert_eq!(held_piece.get_rotation_index(), 0);
+}
+
+#[test]
+fn next_queue_has_the_configured_length() {
+    for &(num_next_pieces, expected_length) in &[(0, 1), (1, 1), (3, 3), (6, 6), (10, 6)] {
+        let mut settings = make_test_settings();
+        settings.num_next_pieces = num_next_pieces;
+
+        let mut game = TestGame::with_settings(settings);
+        assert_eq!(game.model.get_next_pieces().len(), expected_length);
+
+        game.tick();
+        assert_eq!(game.model.get_next_pieces().len(), expected_length);
+    }
+}
+
+#[test]
+fn pieces_spawn_in_queue_order() {
+    let mut game = TestGame::new();
+    let queued_kinds: Vec<PieceKind> = game.model.get_next_pieces()
+        .iter()
+        .map(|piece| *piece.get_kind())
+        .collect();
+
+    game.tick();
+    assert_eq!(game.active_kind(), queued_kinds[0]);
+
+    game.tap(Key::LShift);
+    assert_eq!(game.active_kind(), queued_kinds[1]);
+
+    let next_kind = *game.model.get_next_pieces()[0].get_kind();
+    assert_eq!(next_kind, queued_kinds[2]);
+}
+
+#[test]
+fn hard_drop_locks_the_piece_at_the_bottom() {
+    let mut game = TestGame::new();
+    game.tick();
+
+    let num_rows = game.model.get_board_num_rows() as isize;
+    let expected_distance = (num_rows - 1 - game.active_lowest_row()) as usize;
+
+    game.press(Key::Space);
+    game.tick();
+
+    assert!(game.model.get_active_piece().is_none());
+    assert_eq!(game.model.get_hard_drop_distance(), expected_distance);
+    assert_eq!(game.count_blocks(), 4);
+}
+
+#[test]
+fn simultaneous_presses_resolve_in_binding_order() {
+    let seed = find_seed_with_first_piece(PieceKind::T);
+    let make_settings = || {
+        let mut settings = make_test_settings();
+        settings.seed = seed;
+        settings
+    };
+
+    let mut sequential_game = TestGame::with_settings(make_settings());
+    sequential_game.tick();
+    sequential_game.tap(Key::S);
+    sequential_game.press(Key::Space);
+    sequential_game.tick();
+
+    // Hash based sets iterate in a different order on every run, so repeat
+    // the same frame enough times to catch an unordered resolution
+    for _ in 0..20 {
+        let mut game = TestGame::with_settings(make_settings());
+        game.tick();
+        game.press(Key::S);
+        game.press(Key::Space);
+        game.tick();
+
+        assert!(game.model.get_active_piece().is_none());
+        assert_eq!(game.get_tiles(), sequential_game.get_tiles());
+    }
+}
+
+#[test]
+fn hard_drop_stops_on_the_stack() {
+    let mut game = TestGame::new();
+    game.tick();
+    game.tap(Key::Space);
+    game.tick();
+
+    let num_rows = game.model.get_board_num_rows() as isize;
+    let lowest_row = game.active_lowest_row();
+    game.press(Key::Space);
+    game.tick();
+
+    assert!(game.model.get_hard_drop_distance() < (num_rows - 1 - lowest_row) as usize);
+    assert_eq!(game.count_blocks(), 8);
+}
+
+#[test]
+fn soft_drop_falls_faster_and_counts_rows() {
+    let mut game = TestGame::new();
+    game.tick();
+
+    let initial_row = game.active_lowest_row();
+    game.press(Key::Down);
+
+    for _ in 0..(settings::SOFT_DROP_DELAY_FRAMES * 4) {
+        game.tick();
+    }
+
+    assert_eq!(game.active_lowest_row(), initial_row + 4);
+    assert_eq!(game.model.get_soft_drop_distance(), 4);
+}
+
+#[test]
+fn drop_keys_are_configurable() {
+    let mut settings = make_test_settings();
+    settings.controls.hard_drop = Key::W;
+
+    let mut game = TestGame::with_settings(settings);
+    game.tick();
+
+    game.press(Key::Space);
+    game.tick();
+    assert!(game.model.get_active_piece().is_some());
+
+    game.press(Key::W);
+    game.tick();
+    assert!(game.model.get_active_piece().is_none());
+}
+
+fn make_handling_test_game(das: HandlingDelay, arr: HandlingDelay) -> TestGame {
+    let mut settings = make_test_settings();
+    settings.handling.das = das;
+    settings.handling.arr = arr;
+
+    let mut game = TestGame::with_settings(settings);
+    game.tick();
+
+    game
+}
+
+#[test]
+fn holding_a_direction_waits_for_das_then_repeats_every_arr() {
+    let mut game = make_handling_test_game(HandlingDelay::Frames(10.), HandlingDelay::Frames(2.));
+    let initial_column = game.active_column();
+
+    game.press(Key::Left);
+    game.tick();
+    assert_eq!(game.active_column(), initial_column - 1);
+
+    game.tick_frames(9);
+    assert_eq!(game.active_column(), initial_column - 1);
+
+    game.tick();
+    assert_eq!(game.active_column(), initial_column - 2);
+
+    game.tick();
+    assert_eq!(game.active_column(), initial_column - 2);
+
+    game.tick();
+    assert_eq!(game.active_column(), initial_column - 3);
+}
+
+#[test]
+fn das_can_be_set_in_milliseconds() {
+    let mut game = make_handling_test_game(
+        HandlingDelay::Milliseconds(100.),
+        HandlingDelay::Frames(2.),
+    );
+    let initial_column = game.active_column();
+
+    game.press(Key::Right);
+    game.tick();
+    game.tick_frames(5);
+    assert_eq!(game.active_column(), initial_column + 1);
+
+    game.tick();
+    assert_eq!(game.active_column(), initial_column + 2);
+}
+
+#[test]
+fn sub_frame_arr_moves_several_cells_per_frame() {
+    let mut game = make_handling_test_game(HandlingDelay::Frames(2.), HandlingDelay::Frames(0.5));
+    let initial_column = game.active_column();
+
+    game.press(Key::Right);
+    game.tick_frames(3);
+    assert_eq!(game.active_column(), initial_column + 2);
+
+    game.tick();
+    assert_eq!(game.active_column(), initial_column + 4);
+}
+
+#[test]
+fn zero_arr_moves_the_piece_to_the_wall() {
+    let mut game = make_handling_test_game(HandlingDelay::Frames(2.), HandlingDelay::Frames(0.));
+
+    game.press(Key::Left);
+    game.tick_frames(3);
+
+    let leftmost_column = game.model.get_active_piece()
+        .as_ref()
+        .unwrap()
+        .get_block_iterator()
+        .map(|position| position.column)
+        .min();
+
+    assert_eq!(leftmost_column, Some(0));
+}
+
+#[test]
+fn changing_direction_cancels_the_das_charge() {
+    let mut game = make_handling_test_game(HandlingDelay::Frames(10.), HandlingDelay::Frames(2.));
+
+    game.press(Key::Left);
+    game.tick_frames(11);
+    let column = game.active_column();
+
+    game.press(Key::Right);
+    game.tick();
+    assert_eq!(game.active_column(), column + 1);
+
+    game.tick_frames(9);
+    assert_eq!(game.active_column(), column + 1);
+
+    game.tick();
+    assert_eq!(game.active_column(), column + 2);
+}
+
+#[test]
+fn das_charge_is_preserved_between_pieces() {
+    for &preserve_das_charge in &[true, false] {
+        let mut settings = make_test_settings();
+        settings.handling.preserve_das_charge = preserve_das_charge;
+
+        let mut game = TestGame::with_settings(settings);
+        game.tick();
+
+        game.press(Key::Left);
+        game.tick_frames(11);
+
+        game.press(Key::Space);
+        game.tick();
+        game.release(Key::Space);
+        game.tick();
+
+        let spawn_column = game.active_column();
+        game.tick();
+
+        if preserve_das_charge {
+            assert_eq!(game.active_column(), spawn_column - 1);
+        } else {
+            assert_eq!(game.active_column(), spawn_column);
+        }
+    }
+}
+
+fn make_lock_delay_test_game(policy: LockResetPolicy) -> TestGame {
+    let mut settings = make_test_settings();
+    settings.lock_delay_frames = 30;
+    settings.lock_reset_policy = policy;
+
+    let mut game = TestGame::with_settings(settings);
+    game.tick();
+
+    game
+}
+
+/**
+ * Wiggles the grounded piece left and right, pressing a key every other
+ * frame, and returns the number of frames it takes to lock.
+ */
+fn count_wiggling_frames_until_lock(game: &mut TestGame) -> usize {
+    let wiggle = [Some(Key::Left), None, Some(Key::Right), None];
+    let mut num_frames = 0;
+
+    while game.model.get_active_piece().is_some() {
+        game.release(Key::Left);
+        game.release(Key::Right);
+
+        if let Some(key) = wiggle[num_frames % wiggle.len()] {
+            game.press(key);
+        }
+
+        game.tick();
+        num_frames += 1;
+    }
+
+    num_frames
+}
+
+#[test]
+fn grounded_piece_locks_after_the_lock_delay() {
+    for &policy in &[LockResetPolicy::None, LockResetPolicy::StepReset, LockResetPolicy::MoveReset] {
+        let mut game = make_lock_delay_test_game(policy);
+        game.soft_drop_to_ground();
+
+        game.tick_frames(28);
+        assert!(game.model.get_active_piece().is_some());
+
+        game.tick();
+        assert!(game.model.get_active_piece().is_none());
+    }
+}
+
+#[test]
+fn moves_do_not_reset_the_lock_delay_without_move_reset() {
+    for &policy in &[LockResetPolicy::None, LockResetPolicy::StepReset] {
+        let mut game = make_lock_delay_test_game(policy);
+        game.soft_drop_to_ground();
+
+        assert_eq!(count_wiggling_frames_until_lock(&mut game), 29);
+    }
+}
+
+#[test]
+fn move_reset_is_limited_to_fifteen_resets() {
+    let mut game = make_lock_delay_test_game(LockResetPolicy::MoveReset);
+    game.soft_drop_to_ground();
+
+    // 15 moves on every other frame, then a full lock delay after the last
+    // one, which happens on frame 28
+    assert_eq!(count_wiggling_frames_until_lock(&mut game), 28 + 29 + 1);
+}
+
+#[test]
+fn half_turns_of_the_o_piece_do_not_reset_the_lock_delay() {
+    let mut settings = make_test_settings();
+    settings.seed = find_seed_with_first_piece(PieceKind::O);
+    settings.lock_delay_frames = 30;
+    settings.lock_reset_policy = LockResetPolicy::MoveReset;
+
+    let mut game = TestGame::with_settings(settings);
+    game.tick();
+    assert_eq!(game.active_kind(), PieceKind::O);
+    game.soft_drop_to_ground();
+
+    let mut num_frames = 0;
+
+    while game.model.get_active_piece().is_some() {
+        if num_frames % 2 == 0 {
+            game.press(Key::D);
+        } else {
+            game.release(Key::D);
+        }
+
+        game.tick();
+        num_frames += 1;
+    }
+
+    assert_eq!(num_frames, 29);
+}
+
+#[test]
+fn step_reset_restarts_the_lock_delay_when_falling_off_a_ledge() {
+    for &policy in &[LockResetPolicy::None, LockResetPolicy::StepReset] {
+        let mut settings = make_test_settings();
+        settings.lock_delay_frames = 30;
+        settings.lock_reset_policy = policy;
+        settings.handling.arr = HandlingDelay::Frames(0.);
+
+        let mut game = TestGame::with_settings(settings);
+        game.tick();
+        game.tap(Key::Space);
+        game.tick();
+
+        // The second piece lands on the first one, then slides off its left
+        // edge and falls to the floor
+        game.soft_drop_to_ground();
+        game.press(Key::Left);
+
+        let floor_row = game.model.get_board_num_rows() as isize - 1;
+
+        while game.active_lowest_row() < floor_row || !game.is_grounded() {
+            game.tick();
+        }
+
+        let elapsed_frames = game.model.get_lock_delay().get_elapsed_frames();
+
+        match policy {
+            LockResetPolicy::StepReset => assert_eq!(elapsed_frames, 1),
+            _ => assert!(elapsed_frames > 10),
+        }
+    }
+}
+
+fn make_gravity_test_game(gravity_curve: GravityCurveKind, level: usize) -> TestGame {
+    let mut settings = make_test_settings();
+    settings.gravity_curve = gravity_curve;
+    settings.level = level;
+
+    let mut game = TestGame::with_settings(settings);
+    game.tick();
+
+    game
+}
+
+#[test]
+fn slow_gravity_needs_several_frames_per_row() {
+    // 48 frames per row at level 0 on the NES
+    let mut game = make_gravity_test_game(GravityCurveKind::Nes, 0);
+    let initial_row = game.active_lowest_row();
+
+    game.tick_frames(47);
+    assert_eq!(game.active_lowest_row(), initial_row);
+
+    game.tick();
+    assert_eq!(game.active_lowest_row(), initial_row + 1);
+}
+
+#[test]
+fn fast_gravity_falls_several_rows_per_frame() {
+    // Internal gravity 512, i.e. two rows per frame, at level 300 in TGM
+    let mut game = make_gravity_test_game(GravityCurveKind::Tgm, 300);
+    let initial_row = game.active_lowest_row();
+
+    game.tick();
+    assert_eq!(game.active_lowest_row(), initial_row + 2);
+
+    game.tick_frames(2);
+    assert_eq!(game.active_lowest_row(), initial_row + 6);
+}
+
+fn make_instant_gravity_piece(kind: PieceKind, column: isize, board: &SimpleBoard) -> ActivePiece {
+    let piece = Piece::new(kind, PieceColor::Yellow, 0);
+    let rotation_system = rotations::build_nintendo_rotation_system();
+    let position = BoardPosition::new(0, column);
+    let mut active_piece = ActivePiece::new(piece, position, rotation_system);
+
+    active_piece.enable_instant_gravity(board);
+
+    active_piece
+}
+
+/**
+ * Leftmost column and lowest row of the piece's blocks.
+ */
+fn get_bottom_left(active_piece: &ActivePiece) -> (isize, isize) {
+    let blocks: Vec<_> = active_piece.get_block_iterator().collect();
+    let min_column = blocks.iter().map(|block| block.column).min().unwrap();
+    let max_row = blocks.iter().map(|block| block.row).max().unwrap();
+
+    (min_column, max_row)
+}
+
+#[test]
+fn instant_gravity_drops_the_piece_when_enabled() {
+    let board = SimpleBoard::from_array(&[
+        "00000000",
+        "00000000",
+        "00000000",
+        "00000000",
+        "00000000",
+        "00000000",
+    ]);
+    let active_piece = make_instant_gravity_piece(PieceKind::O, 0, &board);
+
+    assert_eq!(get_bottom_left(&active_piece), (0, 5));
+    assert!(!active_piece.can_move_towards(&Direction::Down, &board));
+}
+
+#[test]
+fn instant_gravity_slides_the_piece_along_the_stack() {
+    let board = SimpleBoard::from_array(&[
+        "00000000",
+        "00000000",
+        "00000000",
+        "00000001",
+        "00000011",
+        "00000111",
+    ]);
+    let mut active_piece = make_instant_gravity_piece(PieceKind::O, 6, &board);
+    assert_eq!(get_bottom_left(&active_piece), (6, 2));
+
+    // Walks down the staircase one step at a time
+    let expected_positions = [(5, 3), (4, 4), (3, 5), (2, 5)];
+
+    for expected_position in expected_positions.iter() {
+        assert!(active_piece.try_move_towards(Direction::Left, &board));
+
+        assert_eq!(get_bottom_left(&active_piece), *expected_position);
+        assert!(!active_piece.can_move_towards(&Direction::Down, &board));
+    }
+
+    // The piece cannot climb back up
+    assert!(active_piece.try_move_towards(Direction::Right, &board));
+    assert!(!active_piece.try_move_towards(Direction::Right, &board));
+    assert_eq!(get_bottom_left(&active_piece), (3, 5));
+}
+
+#[test]
+fn instant_gravity_drops_the_piece_after_a_rotation() {
+    let board = SimpleBoard::from_array(&[
+        "00000000",
+        "00000000",
+        "00000000",
+        "00000000",
+        "00000000",
+        "00000000",
+        "11011111",
+        "11011111",
+    ]);
+    let mut active_piece = make_instant_gravity_piece(PieceKind::I, 0, &board);
+    assert_eq!(get_bottom_left(&active_piece), (0, 5));
+
+    // Standing up puts the piece over the well, where it falls to the bottom
+    assert!(active_piece.try_rotate(RotationDirection::Clockwise, &board));
+    assert_eq!(get_bottom_left(&active_piece), (2, 7));
+}
+
+#[test]
+fn instant_gravity_pieces_reach_the_ground_on_the_spawn_frame() {
+    let mut game = make_gravity_test_game(GravityCurveKind::Instant, 1);
+    let floor_row = game.model.get_board_num_rows() as isize - 1;
+
+    assert_eq!(game.active_lowest_row(), floor_row);
+
+    // The next piece spawns right above the first one and lands on it
+    game.tap(Key::Space);
+    let landing_row = game.active_lowest_row();
+    assert!(landing_row < floor_row);
+
+    game.tick();
+    assert_eq!(game.active_lowest_row(), landing_row);
+    assert!(game.is_grounded());
+}
+
+fn find_seed_with_first_piece(kind: PieceKind) -> u64 {
+    find_seed_with_first_pieces(&|kinds| kinds[0] == kind)
+}
+
+/**
+ * Finds a seed whose first pieces, as many as the queue shows, satisfy the
+ * condition.
+ */
+fn find_seed_with_first_pieces(condition: &dyn Fn(&[PieceKind]) -> bool) -> u64 {
+    (0..)
+        .find(|&seed| {
+            let mut settings = make_test_settings();
+            settings.seed = seed;
+
+            let model = Model::new(settings).unwrap();
+            let kinds: Vec<_> = model.get_next_pieces().iter()
+                .map(|piece| *piece.get_kind())
+                .collect();
+
+            condition(&kinds)
+        })
+        .unwrap()
+}
+
+/**
+ * Settings for a board exactly as wide as the I piece, with a seed that
+ * makes it the first piece.
+ */
+fn make_line_clear_test_settings() -> Settings {
+    let mut settings = make_test_settings();
+    settings.board_size = (4, 20);
+    settings.seed = find_seed_with_first_piece(PieceKind::I);
+
+    settings
+}
+
+#[test]
+fn next_piece_spawns_after_the_entry_delay() {
+    let mut settings = make_test_settings();
+    settings.entry_delay_frames = 10;
+
+    let mut game = TestGame::with_settings(settings);
+    game.tick_frames(11);
+    assert!(game.model.get_active_piece().is_some());
+
+    game.tap(Key::Space);
+    assert_eq!(*game.model.get_phase(), Phase::Entry { remaining_frames: 9 });
+
+    game.tick_frames(8);
+    assert!(game.model.get_active_piece().is_none());
+
+    game.tick();
+    assert!(game.model.get_active_piece().is_some());
+    assert_eq!(*game.model.get_phase(), Phase::Falling);
+}
+
+/**
+ * Hard drops the first piece and returns the number of frames after the
+ * lock until the condition holds.
+ */
+fn count_frames_after_lock(settings: Settings, condition: &dyn Fn(&TestGame) -> bool) -> usize {
+    let mut game = TestGame::with_settings(settings);
+    game.tick();
+    game.press(Key::Space);
+    game.tick();
+    game.release(Key::Space);
+
+    let mut num_frames = 0;
+
+    while !condition(&game) {
+        game.tick();
+        num_frames += 1;
+    }
+
+    num_frames
+}
+
+#[test]
+fn entry_delay_waits_exactly_the_configured_frames() {
+    for num_delay_frames in 0..5 {
+        let mut settings = make_test_settings();
+        settings.entry_delay_frames = num_delay_frames;
+
+        let num_frames = count_frames_after_lock(settings, &|game| {
+            game.model.get_active_piece().is_some()
+        });
+
+        assert_eq!(num_frames, num_delay_frames as usize);
+    }
+}
+
+#[test]
+fn line_clear_delay_waits_exactly_the_configured_frames() {
+    for num_delay_frames in 0..5 {
+        let mut settings = make_line_clear_test_settings();
+        settings.line_clear_delay_frames = num_delay_frames;
+
+        let num_frames = count_frames_after_lock(settings, &|game| game.count_blocks() == 0);
+
+        assert_eq!(num_frames, num_delay_frames as usize);
+    }
+}
+
+#[test]
+fn entry_delay_of_zero_frames_spawns_on_the_lock_frame() {
+    let mut settings = make_test_settings();
+    settings.entry_delay_frames = 0;
+
+    let mut game = TestGame::with_settings(settings);
+    game.tick();
+    let lowest_row = game.active_lowest_row();
+    game.press(Key::Space);
+    game.tick();
+
+    assert_eq!(game.count_blocks(), 4);
+    assert_eq!(*game.model.get_phase(), Phase::Falling);
+    // The next piece starts falling on the next frame
+    assert_eq!(game.active_lowest_row(), lowest_row);
+}
+
+#[test]
+fn entry_delay_of_one_frame_skips_a_single_frame() {
+    let mut settings = make_test_settings();
+    settings.entry_delay_frames = 1;
+
+    let mut game = TestGame::with_settings(settings);
+    game.tick();
+    game.press(Key::Space);
+    game.tick();
+    game.release(Key::Space);
+    assert!(game.model.get_active_piece().is_none());
+
+    game.tick();
+    assert!(game.model.get_active_piece().is_some());
+}
+
+#[test]
+fn line_clear_delays_of_zero_and_one_frames() {
+    for &num_delay_frames in &[0, 1] {
+        let mut settings = make_line_clear_test_settings();
+        settings.line_clear_delay_frames = num_delay_frames;
+        settings.entry_delay_frames = 0;
+
+        let mut game = TestGame::with_settings(settings);
+        game.tick();
+        game.press(Key::Space);
+        game.tick();
+        game.release(Key::Space);
+        assert_eq!(game.count_blocks(), if num_delay_frames == 0 { 0 } else { 4 });
+
+        game.tick();
+        assert_eq!(game.count_blocks(), 0);
+        assert!(game.model.get_active_piece().is_some());
+    }
+}
+
+#[test]
+fn filled_rows_stay_during_the_line_clear_delay() {
+    let mut settings = make_line_clear_test_settings();
+    settings.line_clear_delay_frames = 5;
+    settings.entry_delay_frames = 3;
+
+    let mut game = TestGame::with_settings(settings);
+    game.tick();
+    assert_eq!(game.active_kind(), PieceKind::I);
+
+    game.press(Key::Space);
+    game.tick();
+    assert_eq!(*game.model.get_phase(), Phase::LineClear { remaining_frames: 5 });
+
+    game.tick_frames(4);
+    assert_eq!(game.count_blocks(), 4);
+    assert_eq!(*game.model.get_phase(), Phase::LineClear { remaining_frames: 1 });
+
+    game.tick();
+    assert_eq!(game.count_blocks(), 0);
+    assert_eq!(*game.model.get_phase(), Phase::Entry { remaining_frames: 3 });
+
+    game.tick_frames(2);
+    assert!(game.model.get_active_piece().is_none());
+
+    game.tick();
+    assert!(game.model.get_active_piece().is_some());
+}
+
+#[test]
+fn das_charges_during_the_entry_delay() {
+    let mut settings = make_test_settings();
+    settings.entry_delay_frames = 20;
+
+    let mut game = TestGame::with_settings(settings);
+    game.tick();
+
+    game.press(Key::Space);
+    game.tick();
+    game.release(Key::Space);
+
+    game.press(Key::Left);
+    game.tick_frames(21);
+
+    let spawn_column = game.active_column();
+    game.tick();
+    assert_eq!(game.active_column(), spawn_column - 1);
+}
+
+fn make_initial_action_test_game(initial_rotation: bool, initial_hold: bool) -> TestGame {
+    let mut settings = make_test_settings();
+    settings.entry_delay_frames = 10;
+    settings.initial_rotation = initial_rotation;
+    settings.initial_hold = initial_hold;
+
+    TestGame::with_settings(settings)
+}
+
+fn active_rotation_index(game: &TestGame) -> usize {
+    game.model.get_active_piece().as_ref().unwrap().get_piece().get_rotation_index()
+}
+
+#[test]
+fn rotation_held_during_the_entry_delay_rotates_the_spawned_piece() {
+    for &initial_rotation in &[true, false] {
+        let mut game = make_initial_action_test_game(initial_rotation, true);
+        game.tick();
+        game.tap(Key::Space);
+
+        game.press(Key::S);
+        game.tick_frames(10);
+        assert_eq!(active_rotation_index(&game), if initial_rotation { 1 } else { 0 });
+
+        // The buffered rotation is not applied a second time
+        game.tick();
+        assert_eq!(active_rotation_index(&game), if initial_rotation { 1 } else { 0 });
+    }
+}
+
+#[test]
+fn hold_held_during_the_entry_delay_holds_the_spawned_piece() {
+    for &initial_hold in &[true, false] {
+        let mut game = make_initial_action_test_game(true, initial_hold);
+        game.tick();
+        game.tap(Key::Space);
+
+        let spawning_kind = *game.model.get_next_pieces()[0].get_kind();
+        let following_kind = *game.model.get_next_pieces()[1].get_kind();
+
+        game.press(Key::LShift);
+        game.tick_frames(10);
+
+        if initial_hold {
+            assert_eq!(game.held_kind(), Some(spawning_kind));
+            assert_eq!(game.active_kind(), following_kind);
+        } else {
+            assert_eq!(game.held_kind(), None);
+            assert_eq!(game.active_kind(), spawning_kind);
+        }
+    }
+}
+
+#[test]
+fn initial_rotation_falls_back_to_the_spawn_orientation_when_blocked() {
+    // The standing I piece needs four rows, which the shorter board lacks
+    for &(num_rows, expected_rotation_index) in &[(20, 1), (3, 0)] {
+        let mut settings = make_line_clear_test_settings();
+        settings.board_size = (4, num_rows);
+        settings.buffer_rows = 0;
+
+        let mut game = TestGame::with_settings(settings);
+        game.press(Key::S);
+        game.tick();
+
+        assert_eq!(game.active_kind(), PieceKind::I);
+        assert_eq!(active_rotation_index(&game), expected_rotation_index);
+    }
+}
+
+#[test]
+fn initial_rotation_does_not_kick() {
+    // The rotated T piece only fits one column to the left, through the
+    // hole in the garbage
+    let mut settings = make_test_settings();
+    settings.board_size = (5, 3);
+    settings.buffer_rows = 0;
+    settings.rotation_system = rotations::build_super_rotation_system();
+    settings.seed = find_seed_with_first_piece(PieceKind::T);
+
+    let mut game = TestGame::with_settings(settings);
+    game.model.add_garbage(1, 1);
+    game.press(Key::S);
+    game.tick();
+
+    // The blocked rotation is dropped, but the piece still spawns
+    assert_eq!(*game.model.get_game_over_reason(), None);
+    assert_eq!(*game.model.get_phase(), Phase::Falling);
+    assert_eq!(game.active_kind(), PieceKind::T);
+    assert_eq!(active_rotation_index(&game), 0);
+    assert_eq!(game.active_column(), 1);
+}
+
+#[test]
+fn blocked_rotation_without_kicks_leaves_the_piece_as_it_was() {
+    let board = SimpleBoard::from_array(&[
+        "00000",
+        "00000",
+        "10111",
+    ]);
+    let piece = Piece::new(PieceKind::T, PieceColor::Yellow, 0);
+    let rotation_system = rotations::build_super_rotation_system();
+    let position = BoardPosition::new(0, 1);
+    let mut active_piece = ActivePiece::new(piece, position, rotation_system);
+
+    assert!(!active_piece.try_rotate_without_kicks(RotationDirection::Clockwise, &board));
+    assert_eq!(active_piece.get_piece().get_rotation_index(), 0);
+    assert_eq!(*active_piece.get_position(), position);
+    assert!(active_piece.fits(&board));
+
+    // The same rotation goes through with a kick
+    assert!(active_piece.try_rotate(RotationDirection::Clockwise, &board));
+    assert_eq!(active_piece.get_position().column, 0);
+}
+
+#[test]
+fn initial_rotation_happens_before_instant_gravity() {
+    // The standing I piece fits at spawn, but not once it lies on the floor
+    let mut settings = make_line_clear_test_settings();
+    settings.gravity_curve = GravityCurveKind::Instant;
+
+    let mut game = TestGame::with_settings(settings);
+    game.press(Key::S);
+    game.tick();
+
+    let num_rows = game.model.get_board_num_rows() as isize;
+    assert_eq!(active_rotation_index(&game), 1);
+    assert_eq!(game.active_lowest_row(), num_rows - 1);
+}
+
+#[test]
+fn initial_hold_rescues_a_blocked_spawn() {
+    // A J piece on a single visible row covers the first column of the last
+    // hidden row, which blocks T, L, S and I, but neither O nor Z
+    let seed = find_seed_with_first_pieces(&|kinds| {
+        kinds[0] == PieceKind::J
+            && [PieceKind::T, PieceKind::L, PieceKind::S, PieceKind::I].contains(&kinds[1])
+            && [PieceKind::O, PieceKind::Z].contains(&kinds[2])
+    });
+
+    for &initial_hold in &[true, false] {
+        let mut settings = make_test_settings();
+        settings.board_size = (4, 1);
+        settings.rotation_system = rotations::build_super_rotation_system();
+        settings.initial_hold = initial_hold;
+        settings.seed = seed;
+
+        let mut game = TestGame::with_settings(settings);
+        game.tick();
+
+        let blocked_kind = *game.model.get_next_pieces()[0].get_kind();
+        let rescuing_kind = *game.model.get_next_pieces()[1].get_kind();
+
+        game.press(Key::Space);
+        game.tick();
+        game.release(Key::Space);
+        game.press(Key::LShift);
+        game.tick();
+
+        if initial_hold {
+            assert_eq!(*game.model.get_game_over_reason(), None);
+            assert_eq!(game.held_kind(), Some(blocked_kind));
+            assert_eq!(game.active_kind(), rescuing_kind);
+        } else {
+            assert_eq!(*game.model.get_game_over_reason(), Some(GameOverReason::BlockOut));
+        }
+    }
+}
+
+#[test]
+fn locking_a_piece_reports_a_lock_event_to_the_scoring() {
+    let mut game = TestGame::with_settings(make_line_clear_test_settings());
+    game.tick();
+
+    let num_rows = game.model.get_board_num_rows() as isize;
+    let drop_distance = (num_rows - 1 - game.active_lowest_row()) as usize;
+
+    game.press(Key::Space);
+    game.tick();
+
+    let event = game.model.get_last_lock_event().clone().unwrap();
+    assert_eq!(event.num_cleared_rows, 1);
+    assert_eq!(event.hard_drop_distance, drop_distance);
+    assert_eq!(event.combo, 0);
+    assert!(!event.is_back_to_back);
+
+    assert_eq!(game.model.get_num_cleared_lines(), 1);
+    assert_eq!(game.model.get_score(), 100 + 2 * drop_distance as u64);
+}
+
+/**
+ * Places a piece in its spawn orientation using SRS, then rotates it
+ * clockwise, asserting that the rotation succeeds.
+ */
+fn rotate_into_place(kind: PieceKind, position: BoardPosition, board: &SimpleBoard) -> ActivePiece {
+    let piece = Piece::new(kind, PieceColor::Purple, 0);
+    let rotation_system = rotations::build_super_rotation_system();
+    let mut active_piece = ActivePiece::new(piece, position, rotation_system);
+
+    assert!(active_piece.try_rotate(RotationDirection::Clockwise, board));
+    assert!(active_piece.try_rotate(RotationDirection::Clockwise, board));
+    assert_eq!(*active_piece.get_position(), position);
+
+    active_piece
+}
+
+#[test]
+fn t_spin_with_both_front_corners_is_full() {
+    let board = SimpleBoard::from_array(&[
+        "00000",
+        "10000",
+        "00000",
+        "10111",
+    ]);
+    let active_piece = rotate_into_place(PieceKind::T, BoardPosition::new(1, 0), &board);
+
+    assert_eq!(active_piece.detect_spin(&board, false), Spin::Full);
+}
+
+#[test]
+fn t_spin_with_one_front_corner_is_mini() {
+    let board = SimpleBoard::from_array(&[
+        "00000",
+        "10100",
+        "00000",
+        "10000",
+    ]);
+    let active_piece = rotate_into_place(PieceKind::T, BoardPosition::new(1, 0), &board);
+
+    assert_eq!(active_piece.detect_spin(&board, false), Spin::Mini);
+}
+
+#[test]
+fn t_spin_needs_three_corners() {
+    let board = SimpleBoard::from_array(&[
+        "00000",
+        "00000",
+        "00000",
+        "10111",
+    ]);
+    let active_piece = rotate_into_place(PieceKind::T, BoardPosition::new(1, 0), &board);
+
+    assert_eq!(active_piece.detect_spin(&board, false), Spin::None);
+}
+
+#[test]
+fn t_spin_needs_a_rotation_as_last_action() {
+    let board = SimpleBoard::from_array(&[
+        "00000",
+        "10100",
+        "00000",
+        "10000",
+    ]);
+    let mut active_piece = rotate_into_place(PieceKind::T, BoardPosition::new(1, 0), &board);
+    assert_eq!(active_piece.detect_spin(&board, false), Spin::Mini);
+
+    // Back to the same position, but by moving
+    assert!(active_piece.try_move_towards(Direction::Right, &board));
+    assert!(active_piece.try_move_towards(Direction::Left, &board));
+
+    assert_eq!(*active_piece.get_position(), BoardPosition::new(1, 0));
+    assert_eq!(active_piece.detect_spin(&board, false), Spin::None);
+}
+
+#[test]
+fn t_spin_after_a_two_row_kick_is_full() {
+    // Only the last SRS test fits, moving the piece two rows down into a
+    // slot where just one front corner is occupied
+    let board = SimpleBoard::from_array(&[
+        "01000",
+        "00000",
+        "10100",
+        "00000",
+        "10000",
+    ]);
+    let piece = Piece::new(PieceKind::T, PieceColor::Purple, 0);
+    let rotation_system = rotations::build_super_rotation_system();
+    let mut active_piece = ActivePiece::new(piece, BoardPosition::new(0, 1), rotation_system);
+
+    assert!(active_piece.try_rotate(RotationDirection::Clockwise, &board));
+    assert_eq!(*active_piece.get_position(), BoardPosition::new(2, 0));
+    assert_eq!(active_piece.detect_spin(&board, false), Spin::Full);
+}
+
+#[test]
+fn t_spin_after_a_straight_two_row_kick_stays_mini() {
+    // Only the fourth SRS test fits, moving the piece two rows straight
+    // down into a slot where just one front corner is occupied
+    let board = SimpleBoard::from_array(&[
+        "0100",
+        "0000",
+        "1000",
+        "1000",
+        "1010",
+    ]);
+    let piece = Piece::new(PieceKind::T, PieceColor::Purple, 2);
+    let rotation_system = rotations::build_super_rotation_system();
+    let mut active_piece = ActivePiece::new(piece, BoardPosition::new(0, 0), rotation_system);
+
+    assert!(active_piece.try_rotate(RotationDirection::Counterclockwise, &board));
+    assert_eq!(*active_piece.get_position(), BoardPosition::new(2, 0));
+    assert_eq!(active_piece.detect_spin(&board, false), Spin::Mini);
+}
+
+#[test]
+fn immobile_pieces_are_all_spins_only_when_enabled() {
+    let board = SimpleBoard::from_array(&[
+        "100",
+        "000",
+        "110",
+    ]);
+    let piece = Piece::new(PieceKind::S, PieceColor::Green, 0);
+    let rotation_system = rotations::build_super_rotation_system();
+    let mut active_piece = ActivePiece::new(piece, BoardPosition::new(0, 0), rotation_system);
+
+    assert!(active_piece.try_rotate(RotationDirection::Clockwise, &board));
+    assert_eq!(active_piece.detect_spin(&board, true), Spin::Mini);
+    assert_eq!(active_piece.detect_spin(&board, false), Spin::None);
+}
+
+#[test]
+fn half_turns_of_the_o_piece_are_not_spins() {
+    let board = SimpleBoard::from_array(&[
+        "1001",
+        "1001",
+        "1111",
+    ]);
+    let piece = Piece::new(PieceKind::O, PieceColor::Yellow, 0);
+    let rotation_system = rotations::build_super_rotation_system();
+    let mut active_piece = ActivePiece::new(piece, BoardPosition::new(0, 1), rotation_system);
+
+    assert!(!active_piece.try_rotate(RotationDirection::Half, &board));
+    assert_eq!(active_piece.detect_spin(&board, true), Spin::None);
+}
+
+#[test]
+fn spawning_into_the_stack_is_a_block_out() {
+    let mut game = TestGame::new();
+    game.tick();
+
+    for _ in 0..100 {
+        if game.model.get_game_over_reason().is_some() {
+            break;
+        }
+
+        game.tap(Key::Space);
+    }
+
+    assert_eq!(*game.model.get_game_over_reason(), Some(GameOverReason::BlockOut));
+    assert!(game.model.get_active_piece().is_none());
+    // The view keeps drawing the hold box and the queue with it
+    game.model.get_rotation_system();
+
+    // Nothing happens anymore
+    let num_blocks = game.count_blocks();
+    game.tap(Key::Space);
+    assert_eq!(game.count_blocks(), num_blocks);
+}
+
+#[test]
+fn pieces_spawn_right_above_the_visible_area() {
+    let mut game = TestGame::new();
+    game.tick();
+
+    let num_hidden_rows = game.model.get_board_num_hidden_rows() as isize;
+    assert_eq!(num_hidden_rows, 20);
+    assert_eq!(game.model.get_board_num_visible_rows(), 20);
+
+    for _ in 0..7 {
+        assert_eq!(game.active_lowest_row(), num_hidden_rows - 1);
+        game.tap(Key::Space);
+    }
+}
+
+#[test]
+fn locking_entirely_above_the_visible_area_is_a_lock_out() {
+    let mut game = TestGame::new();
+    game.tick();
+
+    let num_visible_rows = game.model.get_board_num_visible_rows();
+    game.model.add_garbage(num_visible_rows, 0);
+    assert_eq!(*game.model.get_game_over_reason(), None);
+
+    game.tap(Key::Space);
+
+    assert_eq!(*game.model.get_game_over_reason(), Some(GameOverReason::LockOut));
+}
+
+#[test]
+fn garbage_lifts_the_active_piece() {
+    let mut game = TestGame::new();
+    game.tick();
+
+    let num_rows = game.model.get_board_num_rows() as isize;
+    game.press(Key::Down);
+    while game.active_lowest_row() < num_rows - 10 {
+        game.tick();
+    }
+    game.release(Key::Down);
+
+    game.model.add_garbage(12, 0);
+
+    assert_eq!(game.active_lowest_row(), num_rows - 13);
+    assert_eq!(*game.model.get_game_over_reason(), None);
+}
+
+#[test]
+fn garbage_pushing_the_stack_past_the_top_is_a_top_out() {
+    let mut game = TestGame::new();
+    game.tick();
+    game.tap(Key::Space);
+
+    let num_rows = game.model.get_board_num_rows();
+    game.model.add_garbage(num_rows - 1, 0);
+
+    assert_eq!(*game.model.get_game_over_reason(), Some(GameOverReason::TopOut));
+}
+
+#[test]
+fn garbage_without_room_for_the_active_piece_is_a_top_out() {
+    let mut game = TestGame::new();
+    game.tick();
+
+    let num_rows = game.model.get_board_num_rows();
+    game.model.add_garbage(num_rows - 1, 0);
+
+    assert_eq!(*game.model.get_game_over_reason(), Some(GameOverReason::TopOut));
+}
+
+/**
+ * Advances a level on every line and is won after a given number of lines,
+ * so that the tests need not clear whole Marathon levels.
+ */
+struct LevelPerLineMode {
+    num_goal_lines: usize,
+}
+
+impl GameMode for LevelPerLineMode {
+    fn get_level(&self, num_cleared_lines: usize) -> usize {
+        1 + num_cleared_lines
+    }
+
+    fn is_goal_reached(&self, num_cleared_lines: usize) -> bool {
+        num_cleared_lines >= self.num_goal_lines
+    }
+}
+
+#[test]
+fn marathon_starts_at_the_configured_level() {
+    let mut settings = make_test_settings();
+    settings.game_mode = GameModeKind::Marathon;
+    settings.level = 3;
+
+    let game = TestGame::with_settings(settings);
+
+    assert_eq!(game.model.get_level(), 3);
+}
+
+#[test]
+fn clearing_lines_advances_the_level_of_the_game_mode() {
+    let mut game = TestGame::with_settings(make_line_clear_test_settings());
+    game.model.set_game_mode(Box::new(LevelPerLineMode { num_goal_lines: 10 }));
+    game.tick();
+
+    // Scored at the level the line was cleared on
+    game.tap(Key::Space);
+    assert_eq!(game.model.get_last_lock_event().as_ref().unwrap().level, 1);
+    assert_eq!(game.model.get_level(), 2);
+    assert_eq!(game.model.get_stats().level, 2);
+    assert_eq!(game.model.get_stats().num_cleared_lines, 1);
+    assert_eq!(*game.model.get_game_over_reason(), None);
+}
+
+#[test]
+fn reaching_the_goal_of_the_game_mode_ends_the_game() {
+    let mut game = TestGame::with_settings(make_line_clear_test_settings());
+    game.model.set_game_mode(Box::new(LevelPerLineMode { num_goal_lines: 1 }));
+    game.tick();
+    game.tap(Key::Space);
+
+    assert_eq!(*game.model.get_game_over_reason(), Some(GameOverReason::GoalReached));
+    assert_eq!(game.count_blocks(), 0);
+}
+
+#[test]
+fn tick_stops_the_game_loop_once_the_game_is_over() {
+    let mut game = TestGame::new();
+
+    for frame in 0..1000 {
+        // Taps hard drop every other frame
+        if frame % 2 == 0 {
+            game.press(Key::Space);
+        } else {
+            game.release(Key::Space);
+        }
+
+        let is_stopped = game.model.tick(FRAME_DURATION_NANOS);
+        assert_eq!(is_stopped, game.model.get_game_over_reason().is_some());
+
+        if is_stopped {
+            break;
+        }
+    }
+
+    assert_eq!(*game.model.get_game_over_reason(), Some(GameOverReason::BlockOut));
+    assert!(game.model.tick(FRAME_DURATION_NANOS));
+}
+
+#[test]
+fn stats_time_stops_when_the_game_ends() {
+    let mut game = TestGame::new();
+    game.tick_frames(30);
+
+    let elapsed_nanos = 30 * FRAME_DURATION_NANOS;
+    assert_eq!(game.model.get_stats().elapsed_nanos, elapsed_nanos);
+
+    let num_rows = game.model.get_board_num_rows();
+    game.model.add_garbage(num_rows, 0);
+    game.tick_frames(30);
+
+    let stats = game.model.get_stats();
+    assert_eq!(stats.elapsed_nanos, elapsed_nanos);
+    assert_eq!(stats.score, game.model.get_score());
+}
+
+#[test]
+fn waiting_pieces_take_the_colors_of_the_new_level() {
+    let mut settings = make_line_clear_test_settings();
+    settings.color_scheme = ColorSchemeKind::Nes;
+
+    let mut game = TestGame::with_settings(settings);
+    game.model.set_game_mode(Box::new(LevelPerLineMode { num_goal_lines: 10 }));
+    game.tick();
+
+    let next_kind = *game.model.get_next_pieces()[0].get_kind();
+    let level_one_color = NesColorScheme.get_color(&next_kind, 1);
+    let level_two_color = NesColorScheme.get_color(&next_kind, 2);
+    assert_eq!(game.model.get_piece_color(&game.model.get_next_pieces()[0]), level_one_color);
+
+    game.tap(Key::Space);
+    assert_eq!(game.model.get_level(), 2);
+
+    let active_piece = game.model.get_active_piece().as_ref().unwrap();
+    assert_eq!(*active_piece.get_piece().get_kind(), next_kind);
+    assert_eq!(*active_piece.get_color(), level_two_color);
+}
+
+#[test]
+fn cascade_chain_clears_are_counted_and_scored() {
+    let mut game = TestGame::with_settings(make_line_clear_test_settings());
+    game.model.change_gravity(Gravity::Cascade);
+
+    // Once the I piece clears its row, the block above the bottom hole
+    // falls into it and completes the bottom row
+    game.model.add_garbage(1, 1);
+    game.model.add_garbage(1, 0);
+    game.tick();
+    game.tap(Key::Space);
+
+    let num_rows = game.model.get_board_num_rows();
+    let report = game.model.get_last_clear_report().as_ref().unwrap();
+    assert_eq!(report.get_steps(), &[vec![num_rows - 3], vec![num_rows - 1]]);
+
+    let lock_event = game.model.get_last_lock_event().clone().unwrap();
+    let hard_drop_points = 2 * lock_event.hard_drop_distance as u64;
+    assert_eq!(lock_event.num_cleared_rows, 1);
+    assert_eq!(game.model.get_num_cleared_lines(), 2);
+    assert_eq!(game.model.get_score(), 100 + hard_drop_points + 200);
+    assert_eq!(game.count_blocks(), 2);
+}
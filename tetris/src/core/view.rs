@@ -1,7 +1,18 @@
-use sfml::graphics::{Color, IntRect, RenderTarget, RenderWindow, Sprite, Transformable};
+use sfml::graphics::{
+    Color,
+    IntRect,
+    RectangleShape,
+    RenderTarget,
+    RenderWindow,
+    Shape,
+    Sprite,
+    Transformable,
+};
 use sfml::window::{Event, Style};
-use super::super::piece::PieceColor;
+use super::super::helpers;
+use super::super::piece::{Piece, PieceColor};
 use super::super::position::{BoardPosition, WindowPosition};
+use super::super::rotations::RotationSystem;
 use super::super::settings;
 use super::{ActivePiece, GameAssets, Model};
 
@@ -43,6 +54,12 @@ impl View {
 
         self.render_board(model, assets);
         self.render_active_piece(model, assets);
+        self.render_held_piece(model, assets);
+        self.render_next_pieces(model, assets);
+
+        if model.get_game_over_reason().is_some() {
+            self.render_game_over(model);
+        }
 
         self.window.display();
 
@@ -60,11 +77,21 @@ impl View {
         false
     }
 
+    /**
+     * Draws the visible part of the board. Rows in the hidden buffer above
+     * it are skipped.
+     */
     pub fn render_board(&mut self, model: &Model, assets: &mut GameAssets) {
         let mut block_sprite = make_block_sprite(assets);
-        let mut row_index = 0;
+        let num_hidden_rows = model.get_board_num_hidden_rows() as isize;
+        let mut row_index = -num_hidden_rows;
 
         model.for_each_row(&mut |row| {
+            if row_index < 0 {
+                row_index += 1;
+                return;
+            }
+
             row.iter()
                 .enumerate()
                 .for_each(|(tile_index, tile)| {
@@ -83,25 +110,146 @@ impl View {
 
     pub fn render_active_piece(&mut self, model: &Model, assets: &mut GameAssets) {
         if let Some(active_piece) = model.get_active_piece() {
-            self.render_piece(active_piece, assets);
+            self.render_piece(active_piece, model.get_board_num_hidden_rows(), assets);
         }
     }
 
     pub fn render_piece(
         &mut self,
         piece: &ActivePiece,
+        num_hidden_rows: usize,
         assets: &mut GameAssets,
     ) {
         let mut block_sprite = make_block_sprite(assets);
+        let num_hidden_rows = num_hidden_rows as isize;
 
         set_block_color(&mut block_sprite, piece.get_color());
 
         piece.get_block_iterator()
+            .filter(|block_position| block_position.row >= num_hidden_rows)
             .for_each(|block_position| {
-                self.draw_block(&block_position, &mut block_sprite);
+                let visible_position = BoardPosition::new(
+                    block_position.row - num_hidden_rows,
+                    block_position.column,
+                );
+
+                self.draw_block(&visible_position, &mut block_sprite);
             });
     }
 
+    pub fn render_held_piece(&mut self, model: &Model, assets: &mut GameAssets) {
+        let box_position = WindowPosition::new(
+            settings::HOLD_VIEW_POSITION_Y,
+            settings::HOLD_VIEW_POSITION_X,
+        );
+
+        self.draw_box(&box_position, settings::TILE_SCALING);
+
+        if let Some(piece) = model.get_held_piece() {
+            self.render_piece_preview(
+                piece,
+                &model.get_piece_color(piece),
+                model.get_rotation_system(),
+                &box_position,
+                settings::TILE_SCALING,
+                assets,
+            );
+        }
+    }
+
+    pub fn render_next_pieces(&mut self, model: &Model, assets: &mut GameAssets) {
+        let box_size = get_preview_box_size(settings::NEXT_TILE_SCALING);
+
+        for (index, piece) in model.get_next_pieces().iter().enumerate() {
+            let box_position = WindowPosition::new(
+                settings::NEXT_VIEW_POSITION_Y + index as f32 * box_size,
+                settings::NEXT_VIEW_POSITION_X,
+            );
+
+            self.draw_box(&box_position, settings::NEXT_TILE_SCALING);
+            self.render_piece_preview(
+                piece,
+                &model.get_piece_color(piece),
+                model.get_rotation_system(),
+                &box_position,
+                settings::NEXT_TILE_SCALING,
+                assets,
+            );
+        }
+    }
+
+    /**
+     * Dims the board once the game is over.
+     */
+    pub fn render_game_over(&mut self, model: &Model) {
+        let tile_size = settings::TILE_SCALING * settings::TILE_SIZE as f32;
+        let mut shape = RectangleShape::new();
+
+        shape.set_size((
+            model.get_board_num_columns() as f32 * tile_size,
+            model.get_board_num_visible_rows() as f32 * tile_size,
+        ));
+        shape.set_position(self.board_view_position.as_xy());
+        shape.set_fill_color(&Color::rgba(0, 0, 0, 160));
+
+        self.window.draw(&shape);
+    }
+
+    /**
+     * Draws a piece centered inside a side box whose top-left corner is at
+     * the given position.
+     */
+    pub fn render_piece_preview(
+        &mut self,
+        piece: &Piece,
+        color: &PieceColor,
+        rotation_system: &RotationSystem,
+        box_position: &WindowPosition,
+        tile_scaling: f32,
+        assets: &mut GameAssets,
+    ) {
+        let mut block_sprite = make_scaled_block_sprite(assets, tile_scaling);
+        set_block_color(&mut block_sprite, color);
+
+        let origin = BoardPosition::new(0, 0);
+        let blocks: Vec<_> = helpers::get_piece_iterator(piece, &origin, rotation_system).collect();
+
+        let min_row = blocks.iter().map(|block| block.row).min().unwrap_or(0);
+        let max_row = blocks.iter().map(|block| block.row).max().unwrap_or(0);
+        let min_column = blocks.iter().map(|block| block.column).min().unwrap_or(0);
+        let max_column = blocks.iter().map(|block| block.column).max().unwrap_or(0);
+
+        let tile_size = tile_scaling * settings::TILE_SIZE as f32;
+        let box_size = get_preview_box_size(tile_scaling);
+        let piece_height = (max_row - min_row + 1) as f32 * tile_size;
+        let piece_width = (max_column - min_column + 1) as f32 * tile_size;
+        let top = box_position.get_row() + (box_size - piece_height) / 2.;
+        let left = box_position.get_column() + (box_size - piece_width) / 2.;
+
+        for block in blocks {
+            let block_position = WindowPosition::new(
+                top + (block.row - min_row) as f32 * tile_size,
+                left + (block.column - min_column) as f32 * tile_size,
+            );
+
+            block_sprite.set_position(block_position.as_xy());
+            self.window.draw(&block_sprite);
+        }
+    }
+
+    fn draw_box(&mut self, position: &WindowPosition, tile_scaling: f32) {
+        let box_size = get_preview_box_size(tile_scaling);
+        let mut shape = RectangleShape::new();
+
+        shape.set_size((box_size, box_size));
+        shape.set_position(position.as_xy());
+        shape.set_fill_color(&Color::TRANSPARENT);
+        shape.set_outline_color(&Color::WHITE);
+        shape.set_outline_thickness(1.);
+
+        self.window.draw(&shape);
+    }
+
     fn draw_block(&mut self, block_position: &BoardPosition, sprite: &mut Sprite) {
         let block_window_position = self.to_window_coordinates(&block_position);
         let target_position = block_window_position + &self.board_view_position;
@@ -120,14 +268,21 @@ impl View {
     }
 }
 
-fn make_block_sprite(assets: &GameAssets) -> Sprite {
+fn make_block_sprite(assets: &GameAssets) -> Sprite<'_> {
+    make_scaled_block_sprite(assets, settings::TILE_SCALING)
+}
+
+fn make_scaled_block_sprite(assets: &GameAssets, tile_scaling: f32) -> Sprite<'_> {
     let mut block_sprite = assets.make_block_sprite();
-    let tile_scaling = settings::TILE_SCALING;
     block_sprite.scale((tile_scaling, tile_scaling));
 
     block_sprite
 }
 
+fn get_preview_box_size(tile_scaling: f32) -> f32 {
+    (settings::PREVIEW_BOX_NUM_TILES * settings::TILE_SIZE) as f32 * tile_scaling
+}
+
 fn set_block_color(block_sprite: &mut Sprite, color: &PieceColor) {
     let (color_x, color_y) = get_block_color_coordinates(color);
     let tile_size = settings::TILE_SIZE as i32;
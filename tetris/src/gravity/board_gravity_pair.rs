@@ -1,7 +1,14 @@
 use super::super::board::Board;
+use super::ClearReport;
 
 pub trait BoardGravityPair {
     fn board(&self) -> &Board;
     fn board_mut(&mut self) -> &mut Board;
-    fn clear_rows(&mut self, rows: &[usize]);
+
+    /**
+     * Clears the given rows and applies gravity to the remaining blocks,
+     * repeating while falling blocks complete new rows. Returns the rows
+     * cleared in each of those steps.
+     */
+    fn clear_rows(&mut self, rows: &[usize]) -> ClearReport;
 }
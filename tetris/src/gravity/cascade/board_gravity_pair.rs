@@ -0,0 +1,31 @@
+use super::super::super::board::{Board, SimpleBoard};
+use super::super::{BoardGravityPair, ClearReport};
+use super::CascadeGravity;
+
+pub struct CascadeGravityPair {
+    board: SimpleBoard,
+    gravity: CascadeGravity,
+}
+
+impl CascadeGravityPair {
+    pub fn new(board: SimpleBoard, gravity: CascadeGravity) -> CascadeGravityPair {
+        CascadeGravityPair {
+            board,
+            gravity,
+        }
+    }
+}
+
+impl BoardGravityPair for CascadeGravityPair {
+    fn board(&self) -> &dyn Board {
+        &self.board
+    }
+
+    fn board_mut(&mut self) -> &mut dyn Board {
+        &mut self.board
+    }
+
+    fn clear_rows(&mut self, rows: &[usize]) -> ClearReport {
+        self.gravity.clear_rows(&mut self.board, rows)
+    }
+}
@@ -0,0 +1,55 @@
+use super::super::super::board::{Board, SimpleBoard};
+use super::super::super::position::BoardPosition;
+use super::super::ClearReport;
+
+/**
+ * After a line clear, every block that isn't supported from below falls
+ * on its own until it lands. Landing blocks may complete new rows, which
+ * are cleared in turn until the board is stable.
+ */
+pub struct CascadeGravity {}
+
+impl CascadeGravity {
+    pub fn new() -> CascadeGravity {
+        CascadeGravity { }
+    }
+
+    pub fn clear_rows(&self, board: &mut SimpleBoard, rows: &[usize]) -> ClearReport {
+        let mut report = ClearReport::new();
+        let mut rows = rows.to_vec();
+
+        while !rows.is_empty() {
+            board.empty_rows(&rows);
+            report.push_step(rows);
+
+            self.settle(board);
+            rows = board.get_filled_rows();
+        }
+
+        report
+    }
+
+    fn settle(&self, board: &mut SimpleBoard) {
+        let num_rows = board.get_num_rows() as isize;
+        let num_columns = board.get_num_columns() as isize;
+
+        for column in 0..num_columns {
+            let mut landing_row = num_rows - 1;
+
+            for row in (0..num_rows).rev() {
+                let position = BoardPosition::new(row, column);
+
+                if let Some(block) = board.take_block(&position) {
+                    board.put_block(&BoardPosition::new(landing_row, column), block);
+                    landing_row -= 1;
+                }
+            }
+        }
+    }
+}
+
+impl Default for CascadeGravity {
+    fn default() -> CascadeGravity {
+        CascadeGravity::new()
+    }
+}
@@ -0,0 +1,5 @@
+mod board_gravity_pair;
+mod gravity;
+
+pub use self::board_gravity_pair::CascadeGravityPair;
+pub use self::gravity::CascadeGravity;
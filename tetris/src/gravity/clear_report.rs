@@ -0,0 +1,39 @@
+/**
+ * Describes the rows cleared in each step of a cascade. The first step
+ * always corresponds to the rows that triggered the clear; further steps
+ * are rows completed by blocks falling after a previous step.
+ */
+#[derive(Debug, PartialEq)]
+pub struct ClearReport {
+    steps: Vec<Vec<usize>>,
+}
+
+impl ClearReport {
+    pub fn new() -> ClearReport {
+        ClearReport {
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn push_step(&mut self, rows: Vec<usize>) {
+        self.steps.push(rows);
+    }
+
+    pub fn get_steps(&self) -> &[Vec<usize>] {
+        &self.steps
+    }
+
+    pub fn get_num_steps(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn get_num_cleared_rows(&self) -> usize {
+        self.steps.iter().map(|rows| rows.len()).sum()
+    }
+}
+
+impl Default for ClearReport {
+    fn default() -> ClearReport {
+        ClearReport::new()
+    }
+}
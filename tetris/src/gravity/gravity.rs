@@ -1,3 +1,5 @@
 pub enum Gravity {
     Naive,
+    Sticky,
+    Cascade,
 }
@@ -1,6 +1,13 @@
 mod board_gravity_pair;
+mod clear_report;
 mod gravity;
+pub mod cascade;
 pub mod naive;
+pub mod sticky;
+
+#[cfg(test)]
+mod tests;
 
 pub use self::gravity::Gravity;
 pub use self::board_gravity_pair::BoardGravityPair;
+pub use self::clear_report::ClearReport;
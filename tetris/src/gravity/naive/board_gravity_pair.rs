@@ -1,5 +1,5 @@
 use super::super::super::board::{Board, SimpleBoard};
-use super::super::BoardGravityPair;
+use super::super::{BoardGravityPair, ClearReport};
 use super::NaiveGravity;
 
 pub struct NaiveGravityPair {
@@ -25,7 +25,7 @@ impl BoardGravityPair for NaiveGravityPair {
         &mut self.board
     }
 
-    fn clear_rows(&mut self, rows: &[usize]) {
-        self.gravity.clear_rows(&mut self.board, rows);
+    fn clear_rows(&mut self, rows: &[usize]) -> ClearReport {
+        self.gravity.clear_rows(&mut self.board, rows)
     }
 }
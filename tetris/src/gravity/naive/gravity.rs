@@ -1,4 +1,5 @@
 use super::super::super::board::SimpleBoard;
+use super::super::ClearReport;
 
 pub struct NaiveGravity {}
 
@@ -7,7 +8,14 @@ impl NaiveGravity {
         NaiveGravity { }
     }
 
-    pub fn clear_rows(&self, board: &mut SimpleBoard, rows: &[usize]) {
-        // TODO
+    pub fn clear_rows(&self, board: &mut SimpleBoard, rows: &[usize]) -> ClearReport {
+        let mut report = ClearReport::new();
+
+        if !rows.is_empty() {
+            board.remove_rows(rows);
+            report.push_step(rows.to_vec());
+        }
+
+        report
     }
 }
@@ -0,0 +1,31 @@
+use super::super::super::board::{Board, SimpleBoard};
+use super::super::{BoardGravityPair, ClearReport};
+use super::StickyGravity;
+
+pub struct StickyGravityPair {
+    board: SimpleBoard,
+    gravity: StickyGravity,
+}
+
+impl StickyGravityPair {
+    pub fn new(board: SimpleBoard, gravity: StickyGravity) -> StickyGravityPair {
+        StickyGravityPair {
+            board,
+            gravity,
+        }
+    }
+}
+
+impl BoardGravityPair for StickyGravityPair {
+    fn board(&self) -> &dyn Board {
+        &self.board
+    }
+
+    fn board_mut(&mut self) -> &mut dyn Board {
+        &mut self.board
+    }
+
+    fn clear_rows(&mut self, rows: &[usize]) -> ClearReport {
+        self.gravity.clear_rows(&mut self.board, rows)
+    }
+}
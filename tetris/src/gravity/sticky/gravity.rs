@@ -0,0 +1,146 @@
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use super::super::super::board::{Board, SimpleBoard};
+use super::super::super::position::BoardPosition;
+use super::super::ClearReport;
+
+type BlockGroup = Vec<BoardPosition>;
+
+/**
+ * After a line clear, every group of connected blocks falls as a rigid
+ * body until it lands on something. Landing groups may complete new rows,
+ * which are cleared in turn until the board is stable.
+ */
+pub struct StickyGravity {}
+
+impl StickyGravity {
+    pub fn new() -> StickyGravity {
+        StickyGravity { }
+    }
+
+    pub fn clear_rows(&self, board: &mut SimpleBoard, rows: &[usize]) -> ClearReport {
+        let mut report = ClearReport::new();
+        let mut rows = rows.to_vec();
+
+        while !rows.is_empty() {
+            board.empty_rows(&rows);
+            report.push_step(rows);
+
+            self.settle(board);
+            rows = board.get_filled_rows();
+        }
+
+        report
+    }
+
+    fn settle(&self, board: &mut SimpleBoard) {
+        loop {
+            let mut groups = find_groups(board);
+            let mut has_moved = false;
+
+            // Lower groups fall first so that they make room for the ones above
+            groups.sort_by_key(|group| {
+                Reverse(group.iter().map(|position| position.row).max())
+            });
+
+            for group in groups {
+                let distance = get_drop_distance(board, &group);
+
+                if distance > 0 {
+                    move_group(board, &group, distance);
+                    has_moved = true;
+                }
+            }
+
+            if !has_moved {
+                break;
+            }
+        }
+    }
+}
+
+impl Default for StickyGravity {
+    fn default() -> StickyGravity {
+        StickyGravity::new()
+    }
+}
+
+fn find_groups(board: &SimpleBoard) -> Vec<BlockGroup> {
+    let num_rows = board.get_num_rows() as isize;
+    let num_columns = board.get_num_columns() as isize;
+    let mut visited = HashSet::new();
+    let mut groups = Vec::new();
+
+    for row in 0..num_rows {
+        for column in 0..num_columns {
+            let position = BoardPosition::new(row, column);
+
+            if board.is_occupied(&position) && !visited.contains(&position) {
+                groups.push(flood_fill(board, position, &mut visited));
+            }
+        }
+    }
+
+    groups
+}
+
+fn flood_fill(
+    board: &SimpleBoard,
+    start: BoardPosition,
+    visited: &mut HashSet<BoardPosition>,
+) -> BlockGroup {
+    let mut group = Vec::new();
+    let mut pending = vec![start];
+    visited.insert(start);
+
+    while let Some(position) = pending.pop() {
+        group.push(position);
+
+        let neighbors = [
+            BoardPosition::new(position.row - 1, position.column),
+            BoardPosition::new(position.row + 1, position.column),
+            BoardPosition::new(position.row, position.column - 1),
+            BoardPosition::new(position.row, position.column + 1),
+        ];
+
+        for neighbor in neighbors.iter() {
+            if board.is_occupied(neighbor) && !visited.contains(neighbor) {
+                visited.insert(*neighbor);
+                pending.push(*neighbor);
+            }
+        }
+    }
+
+    group
+}
+
+fn get_drop_distance(board: &SimpleBoard, group: &BlockGroup) -> isize {
+    let mut distance = 0;
+
+    loop {
+        let can_fall = group.iter().all(|position| {
+            let target = BoardPosition::new(position.row + distance + 1, position.column);
+
+            board.is_in_bounds(&target)
+            && (!board.is_occupied(&target) || group.contains(&target))
+        });
+
+        if !can_fall {
+            return distance;
+        }
+
+        distance += 1;
+    }
+}
+
+fn move_group(board: &mut SimpleBoard, group: &BlockGroup, distance: isize) {
+    let blocks: Vec<_> = group.iter()
+        .filter_map(|position| board.take_block(position).map(|block| (position, block)))
+        .collect();
+
+    for (position, block) in blocks {
+        let target = BoardPosition::new(position.row + distance, position.column);
+
+        board.put_block(&target, block);
+    }
+}
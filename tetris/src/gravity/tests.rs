@@ -0,0 +1,196 @@
+use super::super::board::SimpleBoard;
+use super::naive::{NaiveGravity, NaiveGravityPair};
+use super::cascade::{CascadeGravity, CascadeGravityPair};
+use super::sticky::{StickyGravity, StickyGravityPair};
+use super::BoardGravityPair;
+
+#[test]
+fn naive_gravity_clears_in_a_single_step() {
+    let mut pair = naive_pair(&[
+        "01100",
+        "01000",
+        "11111",
+        "10111",
+        "11011",
+    ]);
+
+    assert_eq!(pair.clear_rows(&[2]).get_steps(), &[vec![2]]);
+    assert_board_eq(&pair, &[
+        "00000",
+        "01100",
+        "01000",
+        "10111",
+        "11011",
+    ]);
+}
+
+#[test]
+fn naive_gravity_has_no_steps_without_rows() {
+    let mut pair = naive_pair(&["00000", "10100"]);
+
+    assert_eq!(pair.clear_rows(&[]).get_num_steps(), 0);
+    assert_board_eq(&pair, &["00000", "10100"]);
+}
+
+#[test]
+fn sticky_gravity_drops_groups_as_rigid_bodies() {
+    let mut pair = sticky_pair(&[
+        "11100",
+        "11111",
+        "10000",
+        "10000",
+    ]);
+
+    assert_eq!(pair.clear_rows(&[1]).get_steps(), &[vec![1]]);
+    assert_board_eq(&pair, &[
+        "00000",
+        "11100",
+        "10000",
+        "10000",
+    ]);
+}
+
+#[test]
+fn sticky_gravity_drops_groups_independently() {
+    let mut pair = sticky_pair(&[
+        "11000",
+        "00011",
+        "11111",
+        "00000",
+        "10001",
+    ]);
+
+    assert_eq!(pair.clear_rows(&[2]).get_steps(), &[vec![2]]);
+    assert_board_eq(&pair, &[
+        "00000",
+        "00000",
+        "00000",
+        "11011",
+        "10001",
+    ]);
+}
+
+#[test]
+fn sticky_gravity_chains_clears() {
+    let mut pair = sticky_pair(&[
+        "01100",
+        "01000",
+        "11111",
+        "10111",
+        "11011",
+    ]);
+
+    assert_eq!(pair.clear_rows(&[2]).get_steps(), &[vec![2], vec![3]]);
+    assert_board_eq(&pair, &[
+        "00000",
+        "00000",
+        "00000",
+        "01100",
+        "11011",
+    ]);
+}
+
+#[test]
+fn sticky_gravity_has_no_steps_without_rows() {
+    let mut pair = sticky_pair(&["01000", "10100"]);
+
+    assert_eq!(pair.clear_rows(&[]).get_num_steps(), 0);
+    assert_board_eq(&pair, &["01000", "10100"]);
+}
+
+#[test]
+fn cascade_gravity_drops_blocks_individually() {
+    let mut pair = cascade_pair(&[
+        "11100",
+        "11111",
+        "10000",
+        "10000",
+    ]);
+
+    assert_eq!(pair.clear_rows(&[1]).get_steps(), &[vec![1]]);
+    assert_board_eq(&pair, &[
+        "00000",
+        "10000",
+        "10000",
+        "11100",
+    ]);
+}
+
+#[test]
+fn cascade_gravity_chains_clears() {
+    let mut pair = cascade_pair(&[
+        "10000",
+        "11111",
+        "01111",
+    ]);
+
+    let report = pair.clear_rows(&[1]);
+
+    assert_eq!(report.get_steps(), &[vec![1], vec![2]]);
+    assert_eq!(report.get_num_cleared_rows(), 2);
+    assert_board_eq(&pair, &[
+        "00000",
+        "00000",
+        "00000",
+    ]);
+}
+
+#[test]
+fn cascade_gravity_reports_multiple_rows_per_step() {
+    let mut pair = cascade_pair(&[
+        "00100",
+        "11000",
+        "11111",
+        "11111",
+        "00111",
+        "11011",
+    ]);
+
+    let report = pair.clear_rows(&[2, 3]);
+
+    assert_eq!(report.get_steps(), &[vec![2, 3], vec![4, 5]]);
+    assert_eq!(report.get_num_steps(), 2);
+    assert_eq!(report.get_num_cleared_rows(), 4);
+    assert_board_eq(&pair, &[
+        "00000",
+        "00000",
+        "00000",
+        "00000",
+        "00000",
+        "00000",
+    ]);
+}
+
+#[test]
+fn cascade_gravity_has_no_steps_without_rows() {
+    let mut pair = cascade_pair(&["01000", "10100"]);
+
+    assert_eq!(pair.clear_rows(&[]).get_num_steps(), 0);
+    assert_board_eq(&pair, &["01000", "10100"]);
+}
+
+fn naive_pair(rows: &[&str]) -> NaiveGravityPair {
+    NaiveGravityPair::new(SimpleBoard::from_array(rows), NaiveGravity::new())
+}
+
+fn sticky_pair(rows: &[&str]) -> StickyGravityPair {
+    StickyGravityPair::new(SimpleBoard::from_array(rows), StickyGravity::new())
+}
+
+fn cascade_pair(rows: &[&str]) -> CascadeGravityPair {
+    CascadeGravityPair::new(SimpleBoard::from_array(rows), CascadeGravity::new())
+}
+
+fn assert_board_eq(pair: &dyn BoardGravityPair, expected: &[&str]) {
+    let mut rows = Vec::new();
+
+    pair.board().for_each_row(&mut |row| {
+        rows.push(
+            row.iter()
+                .map(|tile| if tile.is_some() { '1' } else { '0' })
+                .collect::<String>()
+        );
+    });
+
+    assert_eq!(rows, expected);
+}
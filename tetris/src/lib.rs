@@ -2,7 +2,11 @@ pub mod board;
 pub mod core;
 pub mod gravity;
 pub mod helpers;
+pub mod mode;
 pub mod piece;
 pub mod position;
+pub mod randomizer;
 pub mod rotations;
+pub mod scoring;
 pub mod settings;
+pub mod speed;
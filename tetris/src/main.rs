@@ -1,4 +1,8 @@
+use std::env;
+use std::process;
 use tetris::core::{GameLoop, GameRenderer, Model};
+use tetris::mode::GameModeKind;
+use tetris::rotations;
 use tetris::settings;
 
 // use tetris::board::SimpleBoard;
@@ -13,11 +17,54 @@ fn main() {
 
     // board.get_filled_rows();
 
-    let settings = settings::make_default_settings();
-    let model = Model::new(settings);
+    let mut settings = settings::make_default_settings();
+    let args: Vec<String> = env::args().collect();
+
+    if let Some(index) = args.iter().position(|arg| arg == "--rotation-system") {
+        let path = args.get(index + 1).unwrap_or_else(|| {
+            eprintln!("Missing path after --rotation-system");
+            process::exit(1);
+        });
+
+        settings.rotation_system = rotations::load_rotation_system(path).unwrap_or_else(|error| {
+            eprintln!("Failed to load rotation system '{}': {}", path, error);
+            process::exit(1);
+        });
+    }
+
+    if let Some(index) = args.iter().position(|arg| arg == "--mode") {
+        settings.game_mode = match args.get(index + 1).map(String::as_str) {
+            Some("free-play") => GameModeKind::FreePlay,
+            Some("marathon") => GameModeKind::Marathon,
+            Some("endless-marathon") => {
+                settings.marathon_end_level = None;
+                GameModeKind::Marathon
+            },
+            _ => {
+                eprintln!("Expected free-play, marathon or endless-marathon after --mode");
+                process::exit(1);
+            },
+        };
+    }
+
+    let model = Model::new(settings).unwrap_or_else(|errors| {
+        for error in errors {
+            eprintln!("Invalid rotation system: {}", error);
+        }
+
+        process::exit(1);
+    });
     let renderer = GameRenderer::new(800, 600, "Tetris");
 
     let mut game_loop = GameLoop::new(model, renderer);
     game_loop.set_update_frequency(60);
     game_loop.start();
+
+    let model = game_loop.get_update();
+
+    if let Some(reason) = model.get_game_over_reason() {
+        println!("Game over ({:?})", reason);
+    }
+
+    println!("{}", model.get_stats());
 }
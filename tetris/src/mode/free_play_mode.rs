@@ -0,0 +1,24 @@
+use super::GameMode;
+
+/**
+ * Plays at a fixed level until the player tops out.
+ */
+pub struct FreePlayMode {
+    level: usize,
+}
+
+impl FreePlayMode {
+    pub fn new(level: usize) -> FreePlayMode {
+        FreePlayMode { level }
+    }
+}
+
+impl GameMode for FreePlayMode {
+    fn get_level(&self, _num_cleared_lines: usize) -> usize {
+        self.level
+    }
+
+    fn is_goal_reached(&self, _num_cleared_lines: usize) -> bool {
+        false
+    }
+}
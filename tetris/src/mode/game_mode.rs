@@ -0,0 +1,8 @@
+/**
+ * Rules on top of the core model: how the level progresses as lines get
+ * cleared, and whether the game has been won.
+ */
+pub trait GameMode {
+    fn get_level(&self, num_cleared_lines: usize) -> usize;
+    fn is_goal_reached(&self, num_cleared_lines: usize) -> bool;
+}
@@ -0,0 +1,4 @@
+pub enum GameModeKind {
+    FreePlay,
+    Marathon,
+}
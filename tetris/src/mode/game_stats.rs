@@ -0,0 +1,34 @@
+use std::fmt;
+
+const NANOSECONDS_PER_MILLISECOND: u64 = 1_000_000;
+const MILLISECONDS_PER_SECOND: u64 = 1_000;
+const SECONDS_PER_MINUTE: u64 = 60;
+
+/**
+ * Summary of a game, reported when it ends.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GameStats {
+    pub score: u64,
+    pub num_cleared_lines: usize,
+    pub level: usize,
+    pub elapsed_nanos: u64,
+}
+
+impl fmt::Display for GameStats {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let elapsed_millis = self.elapsed_nanos / NANOSECONDS_PER_MILLISECOND;
+        let elapsed_seconds = elapsed_millis / MILLISECONDS_PER_SECOND;
+
+        write!(
+            formatter,
+            "Score: {}, Lines: {}, Level: {}, Time: {}:{:02}.{:03}",
+            self.score,
+            self.num_cleared_lines,
+            self.level,
+            elapsed_seconds / SECONDS_PER_MINUTE,
+            elapsed_seconds % SECONDS_PER_MINUTE,
+            elapsed_millis % MILLISECONDS_PER_SECOND,
+        )
+    }
+}
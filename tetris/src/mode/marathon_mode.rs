@@ -0,0 +1,46 @@
+use super::GameMode;
+
+pub const LINES_PER_LEVEL: usize = 10;
+
+/**
+ * Advances one level every ten lines, so that the gravity curve speeds the
+ * pieces up. The game is won once the last level is completed, or goes on
+ * until the player tops out when there is no last level.
+ */
+pub struct MarathonMode {
+    start_level: usize,
+    end_level: Option<usize>,
+}
+
+impl MarathonMode {
+    /**
+     * An end level below the start level is raised to it, so that the game
+     * still lasts for one level.
+     */
+    pub fn new(start_level: usize, end_level: Option<usize>) -> MarathonMode {
+        MarathonMode {
+            start_level,
+            end_level: end_level.map(|end_level| end_level.max(start_level)),
+        }
+    }
+}
+
+impl GameMode for MarathonMode {
+    fn get_level(&self, num_cleared_lines: usize) -> usize {
+        let level = self.start_level + num_cleared_lines / LINES_PER_LEVEL;
+
+        match self.end_level {
+            Some(end_level) => level.min(end_level),
+            None => level,
+        }
+    }
+
+    fn is_goal_reached(&self, num_cleared_lines: usize) -> bool {
+        match self.end_level {
+            Some(end_level) => {
+                self.start_level + num_cleared_lines / LINES_PER_LEVEL > end_level
+            },
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,14 @@
+mod free_play_mode;
+mod game_mode;
+mod game_mode_kind;
+mod game_stats;
+mod marathon_mode;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::free_play_mode::FreePlayMode;
+pub use self::game_mode::GameMode;
+pub use self::game_mode_kind::GameModeKind;
+pub use self::game_stats::GameStats;
+pub use self::marathon_mode::{MarathonMode, LINES_PER_LEVEL};
@@ -0,0 +1,68 @@
+use super::{FreePlayMode, GameMode, GameStats, MarathonMode};
+
+#[test]
+fn free_play_keeps_its_level_forever() {
+    let mode = FreePlayMode::new(7);
+
+    for &num_cleared_lines in &[0, 10, 1000] {
+        assert_eq!(mode.get_level(num_cleared_lines), 7);
+        assert!(!mode.is_goal_reached(num_cleared_lines));
+    }
+}
+
+#[test]
+fn marathon_advances_one_level_every_ten_lines() {
+    let mode = MarathonMode::new(1, Some(15));
+    let cases = [(0, 1), (9, 1), (10, 2), (19, 2), (25, 3), (139, 14), (140, 15)];
+
+    for &(num_cleared_lines, expected_level) in &cases {
+        assert_eq!(mode.get_level(num_cleared_lines), expected_level, "lines: {}", num_cleared_lines);
+    }
+}
+
+#[test]
+fn marathon_counts_levels_from_the_start_level() {
+    let mode = MarathonMode::new(5, Some(15));
+
+    assert_eq!(mode.get_level(0), 5);
+    assert_eq!(mode.get_level(30), 8);
+}
+
+#[test]
+fn marathon_ends_once_the_last_level_is_completed() {
+    let mode = MarathonMode::new(1, Some(15));
+
+    assert!(!mode.is_goal_reached(149));
+    assert!(mode.is_goal_reached(150));
+    assert_eq!(mode.get_level(160), 15);
+}
+
+#[test]
+fn marathon_starting_past_its_end_level_still_plays_one_level() {
+    let mode = MarathonMode::new(20, Some(15));
+
+    assert_eq!(mode.get_level(0), 20);
+    assert!(!mode.is_goal_reached(0));
+    assert!(!mode.is_goal_reached(9));
+    assert!(mode.is_goal_reached(10));
+}
+
+#[test]
+fn endless_marathon_goes_on_until_top_out() {
+    let mode = MarathonMode::new(1, None);
+
+    assert!(!mode.is_goal_reached(10_000));
+    assert_eq!(mode.get_level(200), 21);
+}
+
+#[test]
+fn stats_show_the_time_in_minutes_seconds_and_milliseconds() {
+    let stats = GameStats {
+        score: 12_345,
+        num_cleared_lines: 42,
+        level: 5,
+        elapsed_nanos: 125_678_000_000,
+    };
+
+    assert_eq!(stats.to_string(), "Score: 12345, Lines: 42, Level: 5, Time: 2:05.678");
+}
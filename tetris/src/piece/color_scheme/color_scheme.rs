@@ -0,0 +1,5 @@
+use super::super::{PieceColor, PieceKind};
+
+pub trait ColorScheme {
+    fn get_color(&self, kind: &PieceKind, level: usize) -> PieceColor;
+}
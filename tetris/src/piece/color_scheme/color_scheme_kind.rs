@@ -0,0 +1,4 @@
+pub enum ColorSchemeKind {
+    Guideline,
+    Nes,
+}
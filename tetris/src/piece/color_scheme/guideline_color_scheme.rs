@@ -0,0 +1,21 @@
+use super::super::{PieceColor, PieceKind};
+use super::ColorScheme;
+
+/**
+ * Colors defined by the Tetris Guideline. They don't depend on the level.
+ */
+pub struct GuidelineColorScheme;
+
+impl ColorScheme for GuidelineColorScheme {
+    fn get_color(&self, kind: &PieceKind, _level: usize) -> PieceColor {
+        match kind {
+            PieceKind::I => PieceColor::Cyan,
+            PieceKind::J => PieceColor::Blue,
+            PieceKind::L => PieceColor::Orange,
+            PieceKind::O => PieceColor::Yellow,
+            PieceKind::S => PieceColor::Green,
+            PieceKind::T => PieceColor::Purple,
+            PieceKind::Z => PieceColor::Red,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+#[allow(clippy::module_inception)]
+mod color_scheme;
+mod color_scheme_kind;
+mod guideline_color_scheme;
+mod nes_color_scheme;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::color_scheme::ColorScheme;
+pub use self::color_scheme_kind::ColorSchemeKind;
+pub use self::guideline_color_scheme::GuidelineColorScheme;
+pub use self::nes_color_scheme::NesColorScheme;
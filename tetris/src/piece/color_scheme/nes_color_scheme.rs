@@ -0,0 +1,37 @@
+use super::super::{PieceColor, PieceKind};
+use super::ColorScheme;
+
+/**
+ * Primary and secondary colors of each level in the NES version, which
+ * repeat every ten levels. The original palettes are approximated with
+ * the colors available in the block tileset.
+ */
+const NES_PALETTES: [(PieceColor, PieceColor); 10] = [
+    (PieceColor::Blue, PieceColor::Cyan),
+    (PieceColor::Green, PieceColor::Yellow),
+    (PieceColor::Purple, PieceColor::Red),
+    (PieceColor::Blue, PieceColor::Green),
+    (PieceColor::Red, PieceColor::Green),
+    (PieceColor::Green, PieceColor::Blue),
+    (PieceColor::Red, PieceColor::Orange),
+    (PieceColor::Purple, PieceColor::Red),
+    (PieceColor::Blue, PieceColor::Red),
+    (PieceColor::Red, PieceColor::Orange),
+];
+
+/**
+ * Colors used by the NES version: L and Z use the secondary color of the
+ * current level, every other piece uses the primary one.
+ */
+pub struct NesColorScheme;
+
+impl ColorScheme for NesColorScheme {
+    fn get_color(&self, kind: &PieceKind, level: usize) -> PieceColor {
+        let (primary, secondary) = &NES_PALETTES[level % NES_PALETTES.len()];
+
+        match kind {
+            PieceKind::L | PieceKind::Z => secondary.clone(),
+            _ => primary.clone(),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use super::super::{PieceColor, PieceKind, ALL_PIECE_KINDS};
+use super::{ColorScheme, GuidelineColorScheme, NesColorScheme};
+
+#[test]
+fn guideline_colors() {
+    let scheme = GuidelineColorScheme;
+
+    assert_eq!(scheme.get_color(&PieceKind::I, 0), PieceColor::Cyan);
+    assert_eq!(scheme.get_color(&PieceKind::J, 0), PieceColor::Blue);
+    assert_eq!(scheme.get_color(&PieceKind::L, 0), PieceColor::Orange);
+    assert_eq!(scheme.get_color(&PieceKind::O, 0), PieceColor::Yellow);
+    assert_eq!(scheme.get_color(&PieceKind::S, 0), PieceColor::Green);
+    assert_eq!(scheme.get_color(&PieceKind::T, 0), PieceColor::Purple);
+    assert_eq!(scheme.get_color(&PieceKind::Z, 0), PieceColor::Red);
+}
+
+#[test]
+fn guideline_colors_ignore_the_level() {
+    let scheme = GuidelineColorScheme;
+
+    for kind in ALL_PIECE_KINDS.iter() {
+        for level in 1..30 {
+            assert_eq!(scheme.get_color(kind, level), scheme.get_color(kind, 0));
+        }
+    }
+}
+
+#[test]
+fn nes_colors_split_pieces_into_primary_and_secondary() {
+    let scheme = NesColorScheme;
+
+    for kind in [PieceKind::I, PieceKind::J, PieceKind::O, PieceKind::S, PieceKind::T].iter() {
+        assert_eq!(scheme.get_color(kind, 0), PieceColor::Blue);
+    }
+
+    for kind in [PieceKind::L, PieceKind::Z].iter() {
+        assert_eq!(scheme.get_color(kind, 0), PieceColor::Cyan);
+    }
+}
+
+#[test]
+fn nes_colors_change_with_the_level() {
+    let scheme = NesColorScheme;
+
+    assert_eq!(scheme.get_color(&PieceKind::T, 1), PieceColor::Green);
+    assert_eq!(scheme.get_color(&PieceKind::L, 1), PieceColor::Yellow);
+    assert_eq!(scheme.get_color(&PieceKind::T, 9), PieceColor::Red);
+    assert_eq!(scheme.get_color(&PieceKind::Z, 9), PieceColor::Orange);
+}
+
+#[test]
+fn nes_colors_repeat_every_ten_levels() {
+    let scheme = NesColorScheme;
+
+    for kind in ALL_PIECE_KINDS.iter() {
+        for level in 0..10 {
+            assert_eq!(scheme.get_color(kind, level), scheme.get_color(kind, level + 10));
+            assert_eq!(scheme.get_color(kind, level), scheme.get_color(kind, level + 120));
+        }
+    }
+}
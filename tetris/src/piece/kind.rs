@@ -1,4 +1,4 @@
-#[derive(PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum PieceKind {
     I,
     J,
@@ -8,3 +8,13 @@ pub enum PieceKind {
     T,
     Z,
 }
+
+pub const ALL_PIECE_KINDS: [PieceKind; 7] = [
+    PieceKind::I,
+    PieceKind::J,
+    PieceKind::L,
+    PieceKind::O,
+    PieceKind::S,
+    PieceKind::T,
+    PieceKind::Z,
+];
@@ -2,8 +2,10 @@ mod color;
 mod grid;
 mod kind;
 mod piece;
+pub mod color_scheme;
 
 pub use self::color::PieceColor;
+pub use self::color_scheme::{ColorScheme, ColorSchemeKind};
 pub use self::grid::PieceGrid;
-pub use self::kind::PieceKind;
+pub use self::kind::{PieceKind, ALL_PIECE_KINDS};
 pub use self::piece::Piece;
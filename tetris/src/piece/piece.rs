@@ -24,10 +24,22 @@ impl Piece {
             RotationDirection::Counterclockwise => {
                 self.rotation_index += num_rotations - 1;
                 self.rotation_index %= num_rotations;
+            },
+            RotationDirection::Half => {
+                // Pieces with one or two states end up where they started
+                self.rotation_index += 2;
+                self.rotation_index %= num_rotations;
             }
         }
     }
 
+    /**
+     * Puts the piece back in its spawn orientation.
+     */
+    pub fn reset_rotation(&mut self) {
+        self.rotation_index = 0;
+    }
+
     pub fn get_grid<'a>(&self, rotation_system: &'a RotationSystem) -> &'a PieceGrid {
         let rotation_table = self.get_rotation_table(rotation_system);
 
@@ -38,6 +50,18 @@ impl Piece {
         &self.color
     }
 
+    pub fn set_color(&mut self, color: PieceColor) {
+        self.color = color;
+    }
+
+    pub fn get_kind(&self) -> &PieceKind {
+        &self.kind
+    }
+
+    pub fn get_rotation_index(&self) -> usize {
+        self.rotation_index
+    }
+
     fn get_rotation_table<'a>(&self, rotation_system: &'a RotationSystem) -> &'a RotationTable {
         rotation_system.get(&self.kind).expect("Incomplete rotation system")
     }
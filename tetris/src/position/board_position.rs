@@ -3,6 +3,7 @@ use super::BoardPositionOffset;
 
 // TODO: reduce the redundancy between BoardPosition, PiecePosition and WindowPosition
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct BoardPosition {
     pub row: isize,
     pub column: isize,
@@ -0,0 +1,38 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use super::super::piece::{PieceKind, ALL_PIECE_KINDS};
+use super::Randomizer;
+
+/**
+ * Shuffles all seven piece kinds into a bag and deals them in order,
+ * reshuffling once the bag is empty.
+ */
+pub struct BagRandomizer {
+    rng: StdRng,
+    bag: Vec<PieceKind>,
+}
+
+impl BagRandomizer {
+    pub fn new(seed: u64) -> BagRandomizer {
+        BagRandomizer {
+            rng: StdRng::seed_from_u64(seed),
+            bag: Vec::with_capacity(ALL_PIECE_KINDS.len()),
+        }
+    }
+
+    fn refill(&mut self) {
+        self.bag.extend_from_slice(&ALL_PIECE_KINDS);
+        self.bag.shuffle(&mut self.rng);
+    }
+}
+
+impl Randomizer for BagRandomizer {
+    fn next_piece(&mut self) -> PieceKind {
+        if self.bag.is_empty() {
+            self.refill();
+        }
+
+        self.bag.pop().unwrap()
+    }
+}
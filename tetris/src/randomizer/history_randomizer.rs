@@ -0,0 +1,137 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use super::super::piece::{PieceKind, ALL_PIECE_KINDS};
+use super::Randomizer;
+
+const HISTORY_SIZE: usize = 4;
+const FIRST_PIECE_KINDS: [PieceKind; 4] = [PieceKind::I, PieceKind::J, PieceKind::L, PieceKind::T];
+
+/**
+ * Randomizer used by Tetris The Grand Master. It remembers the last four
+ * pieces and rolls up to four times trying to avoid them. The first piece
+ * is never an S, Z or O.
+ */
+pub struct Tgm1Randomizer {
+    rng: StdRng,
+    history: Vec<PieceKind>,
+    is_first_piece: bool,
+}
+
+impl Tgm1Randomizer {
+    pub fn new(seed: u64) -> Tgm1Randomizer {
+        Tgm1Randomizer {
+            rng: StdRng::seed_from_u64(seed),
+            history: vec![PieceKind::Z; HISTORY_SIZE],
+            is_first_piece: true,
+        }
+    }
+}
+
+impl Randomizer for Tgm1Randomizer {
+    fn next_piece(&mut self) -> PieceKind {
+        let piece = if self.is_first_piece {
+            self.is_first_piece = false;
+            FIRST_PIECE_KINDS[self.rng.gen_range(0..FIRST_PIECE_KINDS.len())]
+        } else {
+            let mut piece = PieceKind::Z;
+
+            for _ in 0..4 {
+                piece = ALL_PIECE_KINDS[self.rng.gen_range(0..ALL_PIECE_KINDS.len())];
+
+                if !self.history.contains(&piece) {
+                    break;
+                }
+            }
+
+            piece
+        };
+
+        push_history(&mut self.history, piece);
+        piece
+    }
+}
+
+/**
+ * Randomizer used by Tetris The Grand Master 3. Pieces are drawn from a
+ * pool of 35 (five of each kind) while avoiding the last four pieces.
+ * Every draw replaces the drawn slot with the piece that has gone the
+ * longest without appearing, which keeps droughts short.
+ */
+pub struct Tgm3Randomizer {
+    rng: StdRng,
+    history: Vec<PieceKind>,
+    pool: Vec<PieceKind>,
+    drought_order: Vec<PieceKind>,
+    is_first_piece: bool,
+}
+
+impl Tgm3Randomizer {
+    pub fn new(seed: u64) -> Tgm3Randomizer {
+        let pool = ALL_PIECE_KINDS.iter()
+            .flat_map(|kind| vec![*kind; 5])
+            .collect();
+
+        Tgm3Randomizer {
+            rng: StdRng::seed_from_u64(seed),
+            history: vec![PieceKind::S, PieceKind::Z, PieceKind::S, PieceKind::Z],
+            pool,
+            drought_order: ALL_PIECE_KINDS.to_vec(),
+            is_first_piece: true,
+        }
+    }
+
+    /**
+     * Returns the pool the next piece will be drawn from.
+     */
+    pub fn get_pool(&self) -> &[PieceKind] {
+        &self.pool
+    }
+}
+
+impl Randomizer for Tgm3Randomizer {
+    fn next_piece(&mut self) -> PieceKind {
+        if self.is_first_piece {
+            self.is_first_piece = false;
+
+            let piece = FIRST_PIECE_KINDS[self.rng.gen_range(0..FIRST_PIECE_KINDS.len())];
+            update_drought_order(&mut self.drought_order, piece);
+            push_history(&mut self.history, piece);
+
+            return piece;
+        }
+
+        let mut index = 0;
+        let mut piece = PieceKind::Z;
+
+        for roll in 0..6 {
+            index = self.rng.gen_range(0..self.pool.len());
+            piece = self.pool[index];
+
+            if !self.history.contains(&piece) || roll == 5 {
+                break;
+            }
+
+            self.pool[index] = self.drought_order[0];
+        }
+
+        update_drought_order(&mut self.drought_order, piece);
+        self.pool[index] = self.drought_order[0];
+
+        push_history(&mut self.history, piece);
+        piece
+    }
+}
+
+/**
+ * Moves the piece to the back of the drought order, so that the front is
+ * always the kind that has gone the longest without being dealt.
+ */
+fn update_drought_order(drought_order: &mut Vec<PieceKind>, piece: PieceKind) {
+    drought_order.retain(|kind| *kind != piece);
+    drought_order.push(piece);
+}
+
+fn push_history(history: &mut Vec<PieceKind>, piece: PieceKind) {
+    history.remove(0);
+    history.push(piece);
+}
@@ -0,0 +1,15 @@
+mod bag_randomizer;
+mod history_randomizer;
+mod nes_randomizer;
+#[allow(clippy::module_inception)]
+mod randomizer;
+mod randomizer_kind;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::bag_randomizer::BagRandomizer;
+pub use self::history_randomizer::{Tgm1Randomizer, Tgm3Randomizer};
+pub use self::nes_randomizer::NesRandomizer;
+pub use self::randomizer::Randomizer;
+pub use self::randomizer_kind::RandomizerKind;
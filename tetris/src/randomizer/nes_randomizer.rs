@@ -0,0 +1,38 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use super::super::piece::{PieceKind, ALL_PIECE_KINDS};
+use super::Randomizer;
+
+/**
+ * Randomizer used by the NES version. It rolls an eight-sided die where
+ * the eighth face means "reroll"; rolling the previous piece also causes
+ * a reroll. The second roll is always accepted.
+ */
+pub struct NesRandomizer {
+    rng: StdRng,
+    previous_piece: Option<PieceKind>,
+}
+
+impl NesRandomizer {
+    pub fn new(seed: u64) -> NesRandomizer {
+        NesRandomizer {
+            rng: StdRng::seed_from_u64(seed),
+            previous_piece: None,
+        }
+    }
+}
+
+impl Randomizer for NesRandomizer {
+    fn next_piece(&mut self) -> PieceKind {
+        let num_kinds = ALL_PIECE_KINDS.len();
+        let roll = self.rng.gen_range(0..num_kinds + 1);
+
+        let piece = match ALL_PIECE_KINDS.get(roll) {
+            Some(kind) if Some(*kind) != self.previous_piece => *kind,
+            _ => ALL_PIECE_KINDS[self.rng.gen_range(0..num_kinds)],
+        };
+
+        self.previous_piece = Some(piece);
+        piece
+    }
+}
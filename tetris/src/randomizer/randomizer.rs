@@ -0,0 +1,9 @@
+use super::super::piece::PieceKind;
+
+/**
+ * Decides which piece comes next. Implementations are expected to be
+ * deterministic for a given seed, so that games can be reproduced.
+ */
+pub trait Randomizer {
+    fn next_piece(&mut self) -> PieceKind;
+}
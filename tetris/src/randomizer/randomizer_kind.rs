@@ -0,0 +1,6 @@
+pub enum RandomizerKind {
+    Bag,
+    Nes,
+    Tgm1,
+    Tgm3,
+}
@@ -0,0 +1,234 @@
+use std::collections::{HashMap, HashSet};
+use super::super::piece::{PieceKind, ALL_PIECE_KINDS};
+use super::{BagRandomizer, NesRandomizer, Randomizer, Tgm1Randomizer, Tgm3Randomizer};
+
+const NUM_DRAWS: usize = 70_000;
+const SEEDS: [u64; 3] = [1, 2019, 987_654_321];
+
+#[test]
+fn bag_deals_every_kind_once_per_bag() {
+    let mut randomizer = BagRandomizer::new(42);
+
+    for _ in 0..1000 {
+        let bag: HashSet<_> = draw(&mut randomizer, 7).into_iter().collect();
+
+        assert_eq!(bag.len(), ALL_PIECE_KINDS.len());
+    }
+}
+
+#[test]
+fn bag_never_repeats_a_kind_more_than_twice_in_a_row() {
+    let pieces = draw(&mut BagRandomizer::new(7), 7000);
+
+    for window in pieces.windows(3) {
+        assert!(window[0] != window[1] || window[1] != window[2]);
+    }
+}
+
+#[test]
+fn bag_never_goes_twelve_pieces_without_a_kind() {
+    let pieces = draw(&mut BagRandomizer::new(1234), 7000);
+
+    for window in pieces.windows(13) {
+        let kinds: HashSet<_> = window.iter().collect();
+
+        assert_eq!(kinds.len(), ALL_PIECE_KINDS.len());
+    }
+}
+
+#[test]
+fn bag_is_reproducible_from_its_seed() {
+    let first = draw(&mut BagRandomizer::new(2019), 70);
+    let second = draw(&mut BagRandomizer::new(2019), 70);
+    let other = draw(&mut BagRandomizer::new(2020), 70);
+
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+}
+
+#[test]
+fn nes_distribution_is_uniform() {
+    for &seed in SEEDS.iter() {
+        assert_uniform(&draw(&mut NesRandomizer::new(seed), NUM_DRAWS));
+    }
+}
+
+#[test]
+fn nes_rerolls_repeats_once() {
+    // A repeat needs a reroll (probability 2/8) that lands on the
+    // previous piece again (probability 1/7), i.e. 1/28 of the draws
+    for &seed in SEEDS.iter() {
+        let repeats = count_repeats(&draw(&mut NesRandomizer::new(seed), NUM_DRAWS));
+
+        assert!(repeats > NUM_DRAWS * 3 / 100, "{} repeats", repeats);
+        assert!(repeats < NUM_DRAWS * 4 / 100, "{} repeats", repeats);
+    }
+}
+
+#[test]
+fn nes_droughts_are_unbounded_but_rare() {
+    for &seed in SEEDS.iter() {
+        let drought = get_max_drought(&draw(&mut NesRandomizer::new(seed), NUM_DRAWS));
+
+        assert!(drought > 30, "max drought {}", drought);
+        assert!(drought < 100, "max drought {}", drought);
+    }
+}
+
+#[test]
+fn tgm1_distribution_is_uniform() {
+    for &seed in SEEDS.iter() {
+        assert_uniform(&draw(&mut Tgm1Randomizer::new(seed), NUM_DRAWS));
+    }
+}
+
+#[test]
+fn tgm1_history_makes_repeats_rare() {
+    for &seed in SEEDS.iter() {
+        let repeats = count_repeats(&draw(&mut Tgm1Randomizer::new(seed), NUM_DRAWS));
+
+        assert!(repeats < NUM_DRAWS * 3 / 100, "{} repeats", repeats);
+    }
+}
+
+#[test]
+fn tgm1_droughts_are_shorter_than_nes() {
+    for &seed in SEEDS.iter() {
+        let drought = get_max_drought(&draw(&mut Tgm1Randomizer::new(seed), NUM_DRAWS));
+
+        assert!(drought < 60, "max drought {}", drought);
+    }
+}
+
+#[test]
+fn tgm3_distribution_is_uniform() {
+    for &seed in SEEDS.iter() {
+        assert_uniform(&draw(&mut Tgm3Randomizer::new(seed), NUM_DRAWS));
+    }
+}
+
+#[test]
+fn tgm3_history_makes_repeats_rarer_than_tgm1() {
+    for &seed in SEEDS.iter() {
+        let repeats = count_repeats(&draw(&mut Tgm3Randomizer::new(seed), NUM_DRAWS));
+
+        assert!(repeats < NUM_DRAWS * 15 / 1000, "{} repeats", repeats);
+    }
+}
+
+#[test]
+fn tgm3_drought_tracking_keeps_droughts_short() {
+    for &seed in SEEDS.iter() {
+        let drought = get_max_drought(&draw(&mut Tgm3Randomizer::new(seed), NUM_DRAWS));
+
+        assert!(drought < 30, "max drought {}", drought);
+    }
+}
+
+#[test]
+fn tgm3_refills_the_pool_with_the_longest_droughted_kind() {
+    let mut randomizer = Tgm3Randomizer::new(2019);
+    let mut dealt = Vec::new();
+    let mut num_checked_draws = 0;
+
+    for _ in 0..30 {
+        let pool_before = randomizer.get_pool().to_vec();
+        dealt.push(randomizer.next_piece());
+
+        let changed_slots: Vec<_> = pool_before.iter()
+            .zip(randomizer.get_pool())
+            .filter(|(before, after)| before != after)
+            .map(|(_, after)| *after)
+            .collect();
+
+        // Rerolls also refill slots, so only draws that changed a single
+        // slot tell which kind went back into the pool
+        if changed_slots.len() == 1 {
+            assert_eq!(changed_slots[0], get_longest_droughted_kind(&dealt), "after {:?}", dealt);
+            num_checked_draws += 1;
+        }
+    }
+
+    assert!(num_checked_draws >= 10, "{} checked draws", num_checked_draws);
+}
+
+#[test]
+fn tgm_first_piece_is_never_s_z_or_o() {
+    let forbidden = [PieceKind::S, PieceKind::Z, PieceKind::O];
+
+    for seed in 0..200 {
+        assert!(!forbidden.contains(&Tgm1Randomizer::new(seed).next_piece()));
+        assert!(!forbidden.contains(&Tgm3Randomizer::new(seed).next_piece()));
+    }
+}
+
+#[test]
+fn history_randomizers_are_reproducible_from_their_seed() {
+    assert_eq!(
+        draw(&mut NesRandomizer::new(5), 100),
+        draw(&mut NesRandomizer::new(5), 100)
+    );
+    assert_eq!(
+        draw(&mut Tgm1Randomizer::new(5), 100),
+        draw(&mut Tgm1Randomizer::new(5), 100)
+    );
+    assert_eq!(
+        draw(&mut Tgm3Randomizer::new(5), 100),
+        draw(&mut Tgm3Randomizer::new(5), 100)
+    );
+}
+
+/**
+ * Checks that every kind shows up within 3% of its expected frequency.
+ */
+fn assert_uniform(pieces: &[PieceKind]) {
+    let mut counts = HashMap::new();
+
+    for piece in pieces {
+        *counts.entry(*piece).or_insert(0) += 1;
+    }
+
+    let expected = pieces.len() / ALL_PIECE_KINDS.len();
+
+    for kind in ALL_PIECE_KINDS.iter() {
+        let count = counts.get(kind).cloned().unwrap_or(0);
+
+        assert!(count > expected * 97 / 100, "{:?} appeared {} times", kind, count);
+        assert!(count < expected * 103 / 100, "{:?} appeared {} times", kind, count);
+    }
+}
+
+fn count_repeats(pieces: &[PieceKind]) -> usize {
+    pieces.windows(2).filter(|pair| pair[0] == pair[1]).count()
+}
+
+/**
+ * Returns the longest run of pieces between two occurrences of a kind.
+ */
+fn get_max_drought(pieces: &[PieceKind]) -> usize {
+    let mut last_seen = HashMap::new();
+    let mut result = 0;
+
+    for (index, piece) in pieces.iter().enumerate() {
+        if let Some(previous_index) = last_seen.insert(*piece, index) {
+            result = result.max(index - previous_index - 1);
+        }
+    }
+
+    result
+}
+
+/**
+ * Returns the kind dealt the longest ago, preferring kinds that were never
+ * dealt in the order of ALL_PIECE_KINDS.
+ */
+fn get_longest_droughted_kind(pieces: &[PieceKind]) -> PieceKind {
+    *ALL_PIECE_KINDS.iter()
+        .min_by_key(|kind| pieces.iter().rposition(|piece| piece == *kind).map_or(0, |index| index + 1))
+        .unwrap()
+}
+
+fn draw(randomizer: &mut dyn Randomizer, count: usize) -> Vec<PieceKind> {
+    (0..count).map(|_| randomizer.next_piece()).collect()
+}
+
@@ -0,0 +1,102 @@
+use super::super::piece::PieceKind;
+use super::{make_kick_table, make_rotation_table, KickTable, RotationSystem, RotationTable};
+
+/**
+ * Arika Rotation System, used by the Tetris The Grand Master series.
+ * L, J and T follow the center column rule and the I piece never kicks.
+ */
+pub fn build_arika_rotation_system() -> RotationSystem {
+    let mut result = RotationSystem::new();
+
+    result.insert(PieceKind::I, arika_rotation_i());
+    result.insert(PieceKind::J, arika_rotation_j());
+    result.insert(PieceKind::L, arika_rotation_l());
+    result.insert(PieceKind::O, arika_rotation_o());
+    result.insert(PieceKind::S, arika_rotation_s());
+    result.insert(PieceKind::T, arika_rotation_t());
+    result.insert(PieceKind::Z, arika_rotation_z());
+
+    result.insert_kicks(PieceKind::J, arika_kicks(4));
+    result.insert_kicks(PieceKind::L, arika_kicks(4));
+    result.insert_kicks(PieceKind::S, arika_kicks(2));
+    result.insert_kicks(PieceKind::T, arika_kicks(4));
+    result.insert_kicks(PieceKind::Z, arika_kicks(2));
+
+    result.enable_center_column_rule(PieceKind::J);
+    result.enable_center_column_rule(PieceKind::L);
+    result.enable_center_column_rule(PieceKind::T);
+
+    result
+}
+
+fn arika_rotation_i() -> RotationTable {
+    make_rotation_table(&[
+        &["0000", "1111", "0000", "0000"],
+        &["0010", "0010", "0010", "0010"],
+    ])
+}
+
+fn arika_rotation_j() -> RotationTable {
+    make_rotation_table(&[
+        &["000", "111", "001"],
+        &["010", "010", "110"],
+        &["000", "100", "111"],
+        &["011", "010", "010"],
+    ])
+}
+
+fn arika_rotation_l() -> RotationTable {
+    make_rotation_table(&[
+        &["000", "111", "100"],
+        &["110", "010", "010"],
+        &["000", "001", "111"],
+        &["010", "010", "011"],
+    ])
+}
+
+fn arika_rotation_o() -> RotationTable {
+    make_rotation_table(&[
+        &["11", "11"],
+    ])
+}
+
+fn arika_rotation_s() -> RotationTable {
+    make_rotation_table(&[
+        &["000", "011", "110"],
+        &["100", "110", "010"],
+    ])
+}
+
+fn arika_rotation_t() -> RotationTable {
+    make_rotation_table(&[
+        &["000", "111", "010"],
+        &["010", "110", "010"],
+        &["000", "010", "111"],
+        &["010", "011", "010"],
+    ])
+}
+
+fn arika_rotation_z() -> RotationTable {
+    make_rotation_table(&[
+        &["000", "110", "011"],
+        &["001", "011", "010"],
+    ])
+}
+
+/**
+ * Every failed rotation tries one cell to the right, then one cell to
+ * the left, regardless of the states involved.
+ */
+fn arika_kicks(num_states: usize) -> KickTable {
+    let offsets: &[(isize, isize)] = &[(1, 0), (-1, 0)];
+    let mut transitions = Vec::new();
+
+    for state in 0..num_states {
+        let next_state = (state + 1) % num_states;
+
+        transitions.push(((state, next_state), offsets));
+        transitions.push(((next_state, state), offsets));
+    }
+
+    make_kick_table(&transitions)
+}
@@ -0,0 +1,17 @@
+use std::collections::HashMap;
+use super::super::position::BoardPositionOffset;
+
+/**
+ * Maps a (from_state, to_state) pair to the offsets tried, in order, when
+ * the rotation between those states collides. Half turns use their own
+ * pairs, e.g. (0, 2), so their kicks are optional and independent from
+ * the ones used by quarter turns.
+ */
+pub type KickTable = HashMap<StateTransition, Vec<BoardPositionOffset>>;
+
+// A (from_state, to_state) pair
+pub type StateTransition = (usize, usize);
+
+// A kick offset in the usual (x, y) notation of kick tables, where positive
+// x goes right and positive y goes up
+pub type KickOffset = (isize, isize);
@@ -0,0 +1,20 @@
+use super::super::position::BoardPositionOffset;
+use super::{KickOffset, KickTable, StateTransition};
+
+/**
+ * Builds a kick table from offsets written in the usual (x, y) notation,
+ * where positive x goes right and positive y goes up.
+ */
+pub fn make_kick_table(transitions: &[(StateTransition, &[KickOffset])]) -> KickTable {
+    let mut result = KickTable::new();
+
+    for (states, offsets) in transitions {
+        let parsed_offsets = offsets.iter()
+            .map(|&(x, y)| BoardPositionOffset::new(-y, x))
+            .collect();
+
+        result.insert(*states, parsed_offsets);
+    }
+
+    result
+}
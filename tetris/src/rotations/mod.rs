@@ -1,15 +1,27 @@
+mod arika_rotations;
+mod kick_table;
+mod make_kick_table;
 mod make_rotation_table;
 mod nintendo_rotations;
 mod rotation_direction;
 mod rotation_system;
 mod rotation_table;
+mod super_rotations;
+pub mod parser;
+pub mod validator;
 
 #[cfg(test)]
 mod tests;
 
+pub use self::arika_rotations::build_arika_rotation_system;
+pub use self::kick_table::{KickOffset, KickTable, StateTransition};
 pub use self::nintendo_rotations::build_nintendo_rotation_system;
+pub use self::parser::{load_rotation_system, parse_rotation_system};
 pub use self::rotation_direction::RotationDirection;
 pub use self::rotation_system::RotationSystem;
 pub use self::rotation_table::RotationTable;
+pub use self::super_rotations::build_super_rotation_system;
+pub use self::validator::{validate, RotationSystemError};
 
+use self::make_kick_table::make_kick_table;
 use self::make_rotation_table::make_rotation_table;
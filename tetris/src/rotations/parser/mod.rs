@@ -0,0 +1,8 @@
+mod parse_error;
+mod rotation_system_parser;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::parse_error::{LoadError, ParseError, ParseErrorKind};
+pub use self::rotation_system_parser::{load_rotation_system, parse_rotation_system};
@@ -0,0 +1,92 @@
+use std::fmt;
+use std::io;
+use super::super::RotationSystemError;
+
+#[derive(Debug, PartialEq)]
+pub enum ParseErrorKind {
+    UnknownDirective(String),
+    UnknownPieceKind(String),
+    DuplicatePiece(String),
+    DuplicateKick(String),
+    MissingPiece,
+    EmptyPiece,
+    MissingArgument,
+    UnexpectedArgument(String),
+    InvalidGrid(String),
+    InvalidTransition(String),
+    InvalidOffset(String),
+}
+
+/**
+ * Error found while parsing a rotation system file. Lines and columns
+ * are 1-based and point to the offending token.
+ */
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug)]
+pub enum LoadError {
+    Io(io::Error),
+    Parse(ParseError),
+    Invalid(Vec<RotationSystemError>),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseErrorKind::UnknownDirective(name) => write!(f, "unknown directive '{}'", name),
+            ParseErrorKind::UnknownPieceKind(name) => write!(f, "unknown piece kind '{}'", name),
+            ParseErrorKind::DuplicatePiece(name) => write!(f, "piece '{}' is defined twice", name),
+            ParseErrorKind::DuplicateKick(transition) => write!(f, "kick '{}' is defined twice", transition),
+            ParseErrorKind::MissingPiece => write!(f, "directive used before any 'piece'"),
+            ParseErrorKind::EmptyPiece => write!(f, "piece has no states"),
+            ParseErrorKind::MissingArgument => write!(f, "missing argument"),
+            ParseErrorKind::UnexpectedArgument(value) => write!(f, "unexpected argument '{}'", value),
+            ParseErrorKind::InvalidGrid(value) => write!(f, "invalid grid '{}'", value),
+            ParseErrorKind::InvalidTransition(value) => write!(f, "invalid transition '{}'", value),
+            ParseErrorKind::InvalidOffset(value) => write!(f, "invalid offset '{}'", value),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.kind)
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::Io(error) => write!(f, "{}", error),
+            LoadError::Parse(error) => write!(f, "{}", error),
+            LoadError::Invalid(errors) => {
+                let messages: Vec<_> = errors.iter().map(|error| error.to_string()).collect();
+
+                write!(f, "{}", messages.join("; "))
+            },
+        }
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(error: io::Error) -> LoadError {
+        LoadError::Io(error)
+    }
+}
+
+impl From<ParseError> for LoadError {
+    fn from(error: ParseError) -> LoadError {
+        LoadError::Parse(error)
+    }
+}
+
+impl From<Vec<RotationSystemError>> for LoadError {
+    fn from(errors: Vec<RotationSystemError>) -> LoadError {
+        LoadError::Invalid(errors)
+    }
+}
@@ -0,0 +1,340 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use super::super::super::piece::{PieceGrid, PieceKind};
+use super::super::{
+    make_kick_table,
+    validate,
+    KickOffset,
+    RotationSystem,
+    RotationTable,
+    StateTransition,
+};
+use super::{LoadError, ParseError, ParseErrorKind};
+
+struct Token<'a> {
+    text: &'a str,
+    column: usize,
+}
+
+struct PieceDefinition {
+    kind: PieceKind,
+    rotation_table: RotationTable,
+    kicks: Vec<(StateTransition, Vec<KickOffset>)>,
+    center_column_rule: bool,
+    line: usize,
+    column: usize,
+}
+
+/**
+ * Reads a rotation system from a file and validates it. See
+ * `parse_rotation_system` for the file format.
+ */
+pub fn load_rotation_system<P: AsRef<Path>>(path: P) -> Result<RotationSystem, LoadError> {
+    let source = fs::read_to_string(path)?;
+    let rotation_system = parse_rotation_system(&source)?;
+
+    validate(&rotation_system)?;
+
+    Ok(rotation_system)
+}
+
+/**
+ * Parses a rotation system. The format is line-based: empty lines are
+ * ignored and '#' starts a comment that goes until the end of the line.
+ * Every other line is a directive followed by whitespace-separated
+ * arguments:
+ *
+ *   piece <I|J|L|O|S|T|Z>     starts the definition of a piece kind
+ *   state <grid>              adds a state to the current piece, e.g.
+ *                             "010/111/000" ('1' is a block, '/' ends a row)
+ *   kick <from>-><to> <x>,<y>...
+ *                             offsets tried in order when rotating from
+ *                             one state to another collides, with positive
+ *                             x going right and positive y going up
+ *   center-column             enables the center column rule for the
+ *                             current piece
+ *
+ * States are numbered from 0 in the order they are declared. Rotating
+ * clockwise goes to the next state and a half turn skips one, so kicks
+ * for half turns are declared as e.g. "kick 0->2 ...".
+ */
+pub fn parse_rotation_system(source: &str) -> Result<RotationSystem, ParseError> {
+    let mut result = RotationSystem::new();
+    let mut defined_kinds = HashSet::new();
+    let mut current_piece: Option<PieceDefinition> = None;
+
+    for (line_index, line_content) in source.lines().enumerate() {
+        let line = line_index + 1;
+        let tokens = tokenize(line_content);
+
+        let (directive, arguments) = match tokens.split_first() {
+            Some(split) => split,
+            None => continue,
+        };
+
+        match directive.text {
+            "piece" => {
+                let argument = expect_single_argument(line, directive, arguments)?;
+                let kind = parse_piece_kind(line, argument)?;
+
+                if !defined_kinds.insert(kind) {
+                    return Err(ParseError {
+                        line,
+                        column: argument.column,
+                        kind: ParseErrorKind::DuplicatePiece(argument.text.to_string()),
+                    });
+                }
+
+                let definition = PieceDefinition {
+                    kind,
+                    rotation_table: Vec::new(),
+                    kicks: Vec::new(),
+                    center_column_rule: false,
+                    line,
+                    column: directive.column,
+                };
+
+                if let Some(previous_piece) = current_piece.replace(definition) {
+                    finish_piece(previous_piece, &mut result)?;
+                }
+            },
+            "state" => {
+                let piece = get_current_piece(&mut current_piece, line, directive)?;
+                let argument = expect_single_argument(line, directive, arguments)?;
+
+                piece.rotation_table.push(parse_grid(line, argument)?);
+            },
+            "kick" => {
+                let piece = get_current_piece(&mut current_piece, line, directive)?;
+
+                let (transition, offsets) = match arguments.split_first() {
+                    Some(split) => split,
+                    None => return Err(missing_argument(line, directive)),
+                };
+
+                let states = parse_transition(line, transition)?;
+
+                if piece.kicks.iter().any(|(defined_states, _)| *defined_states == states) {
+                    return Err(ParseError {
+                        line,
+                        column: transition.column,
+                        kind: ParseErrorKind::DuplicateKick(transition.text.to_string()),
+                    });
+                }
+
+                let offsets = offsets.iter()
+                    .map(|offset| parse_offset(line, offset))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                piece.kicks.push((states, offsets));
+            },
+            "center-column" => {
+                let piece = get_current_piece(&mut current_piece, line, directive)?;
+
+                if let Some(argument) = arguments.first() {
+                    return Err(unexpected_argument(line, argument));
+                }
+
+                piece.center_column_rule = true;
+            },
+            _ => {
+                return Err(ParseError {
+                    line,
+                    column: directive.column,
+                    kind: ParseErrorKind::UnknownDirective(directive.text.to_string()),
+                });
+            }
+        }
+    }
+
+    if let Some(piece) = current_piece {
+        finish_piece(piece, &mut result)?;
+    }
+
+    Ok(result)
+}
+
+fn tokenize(line: &str) -> Vec<Token<'_>> {
+    let content = match line.find('#') {
+        Some(comment_start) => &line[..comment_start],
+        None => line,
+    };
+
+    let mut result = Vec::new();
+    let mut token_start = None;
+
+    for (byte_index, character) in content.char_indices() {
+        match (character.is_whitespace(), token_start) {
+            (false, None) => token_start = Some(byte_index),
+            (true, Some(start)) => {
+                result.push(make_token(content, start, byte_index));
+                token_start = None;
+            },
+            _ => {},
+        }
+    }
+
+    if let Some(start) = token_start {
+        result.push(make_token(content, start, content.len()));
+    }
+
+    result
+}
+
+fn make_token(content: &str, start: usize, end: usize) -> Token<'_> {
+    Token {
+        text: &content[start..end],
+        column: content[..start].chars().count() + 1,
+    }
+}
+
+fn finish_piece(piece: PieceDefinition, result: &mut RotationSystem) -> Result<(), ParseError> {
+    if piece.rotation_table.is_empty() {
+        return Err(ParseError {
+            line: piece.line,
+            column: piece.column,
+            kind: ParseErrorKind::EmptyPiece,
+        });
+    }
+
+    if !piece.kicks.is_empty() {
+        let kicks: Vec<_> = piece.kicks.iter()
+            .map(|(states, offsets)| (*states, offsets.as_slice()))
+            .collect();
+
+        result.insert_kicks(piece.kind, make_kick_table(&kicks));
+    }
+
+    if piece.center_column_rule {
+        result.enable_center_column_rule(piece.kind);
+    }
+
+    result.insert(piece.kind, piece.rotation_table);
+
+    Ok(())
+}
+
+fn get_current_piece<'a>(
+    current_piece: &'a mut Option<PieceDefinition>,
+    line: usize,
+    directive: &Token,
+) -> Result<&'a mut PieceDefinition, ParseError> {
+    current_piece.as_mut().ok_or(ParseError {
+        line,
+        column: directive.column,
+        kind: ParseErrorKind::MissingPiece,
+    })
+}
+
+fn expect_single_argument<'a, 'b>(
+    line: usize,
+    directive: &Token,
+    arguments: &'a [Token<'b>],
+) -> Result<&'a Token<'b>, ParseError> {
+    match arguments {
+        [] => Err(missing_argument(line, directive)),
+        [argument] => Ok(argument),
+        [_, extra, ..] => Err(unexpected_argument(line, extra)),
+    }
+}
+
+fn parse_piece_kind(line: usize, token: &Token) -> Result<PieceKind, ParseError> {
+    match token.text {
+        "I" => Ok(PieceKind::I),
+        "J" => Ok(PieceKind::J),
+        "L" => Ok(PieceKind::L),
+        "O" => Ok(PieceKind::O),
+        "S" => Ok(PieceKind::S),
+        "T" => Ok(PieceKind::T),
+        "Z" => Ok(PieceKind::Z),
+        _ => Err(ParseError {
+            line,
+            column: token.column,
+            kind: ParseErrorKind::UnknownPieceKind(token.text.to_string()),
+        }),
+    }
+}
+
+fn parse_grid(line: usize, token: &Token) -> Result<PieceGrid, ParseError> {
+    let mut result = PieceGrid(Vec::new());
+    let mut row_length = None;
+
+    for (row_start, row) in split_with_columns(token, '/') {
+        let is_valid_row = !row.is_empty()
+            && row_length.is_none_or(|length| length == row.len())
+            && row.chars().all(|value| value == '0' || value == '1');
+
+        if !is_valid_row {
+            return Err(ParseError {
+                line,
+                column: row_start,
+                kind: ParseErrorKind::InvalidGrid(token.text.to_string()),
+            });
+        }
+
+        row_length = Some(row.len());
+        result.0.extend(row.chars().map(|value| value == '1'));
+    }
+
+    Ok(result)
+}
+
+fn parse_transition(line: usize, token: &Token) -> Result<StateTransition, ParseError> {
+    let states: Vec<_> = token.text.split("->").map(|state| state.parse::<usize>()).collect();
+
+    match states.as_slice() {
+        [Ok(from_state), Ok(to_state)] => Ok((*from_state, *to_state)),
+        _ => Err(ParseError {
+            line,
+            column: token.column,
+            kind: ParseErrorKind::InvalidTransition(token.text.to_string()),
+        }),
+    }
+}
+
+fn parse_offset(line: usize, token: &Token) -> Result<KickOffset, ParseError> {
+    let coordinates: Vec<_> = token.text.split(',').map(|value| value.parse::<isize>()).collect();
+
+    match coordinates.as_slice() {
+        [Ok(x), Ok(y)] => Ok((*x, *y)),
+        _ => Err(ParseError {
+            line,
+            column: token.column,
+            kind: ParseErrorKind::InvalidOffset(token.text.to_string()),
+        }),
+    }
+}
+
+/**
+ * Splits a token, returning each part along with the column it starts at.
+ */
+fn split_with_columns<'a>(
+    token: &'a Token,
+    separator: char,
+) -> impl Iterator<Item = (usize, &'a str)> {
+    let mut column = token.column;
+
+    token.text.split(separator).map(move |part| {
+        let part_column = column;
+        column += part.chars().count() + 1;
+
+        (part_column, part)
+    })
+}
+
+fn missing_argument(line: usize, directive: &Token) -> ParseError {
+    ParseError {
+        line,
+        column: directive.column + directive.text.chars().count(),
+        kind: ParseErrorKind::MissingArgument,
+    }
+}
+
+fn unexpected_argument(line: usize, argument: &Token) -> ParseError {
+    ParseError {
+        line,
+        column: argument.column,
+        kind: ParseErrorKind::UnexpectedArgument(argument.text.to_string()),
+    }
+}
@@ -0,0 +1,223 @@
+use super::super::super::piece::{PieceColor, Piece, PieceGrid, PieceKind};
+use super::super::{build_super_rotation_system, RotationDirection, RotationSystem};
+use std::env;
+use std::fs;
+use super::super::RotationSystemError;
+use super::{load_rotation_system, parse_rotation_system, LoadError, ParseError, ParseErrorKind};
+
+const ALL_KINDS: [PieceKind; 7] = [
+    PieceKind::I,
+    PieceKind::J,
+    PieceKind::L,
+    PieceKind::O,
+    PieceKind::S,
+    PieceKind::T,
+    PieceKind::Z,
+];
+
+#[test]
+fn parses_grids_kicks_and_flags() {
+    let rotation_system = parse_rotation_system("
+        # A comment
+        piece T
+        state 000/111/010   # trailing comment
+        state 010/110/010
+        kick 0->1 1,0 -1,0
+        center-column
+
+        piece O
+        state 11/11
+    ").unwrap();
+
+    let mut piece = Piece::new(PieceKind::T, PieceColor::Purple, 0);
+    assert_eq!(readable_grid(piece.get_grid(&rotation_system)), "000111010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_eq!(readable_grid(piece.get_grid(&rotation_system)), "010110010");
+
+    let kicks = rotation_system.get_kicks(&PieceKind::T, 0, 1);
+    assert_eq!(kicks.len(), 2);
+    assert_eq!((kicks[0].get_row(), kicks[0].get_column()), (0, 1));
+    assert_eq!((kicks[1].get_row(), kicks[1].get_column()), (0, -1));
+    assert!(rotation_system.get_kicks(&PieceKind::T, 1, 0).is_empty());
+
+    assert!(rotation_system.has_center_column_rule(&PieceKind::T));
+    assert!(!rotation_system.has_center_column_rule(&PieceKind::O));
+    assert_eq!(rotation_system.get(&PieceKind::O).unwrap().len(), 1);
+    assert!(rotation_system.get(&PieceKind::I).is_none());
+}
+
+#[test]
+fn super_rotation_file_matches_builtin_system() {
+    let source = include_str!("../../../../resources/rotations/super.txt");
+    let parsed = parse_rotation_system(source).unwrap();
+    let builtin = build_super_rotation_system();
+
+    for kind in ALL_KINDS.iter() {
+        assert_same_piece(kind, &parsed, &builtin);
+    }
+}
+
+#[test]
+fn reports_unknown_directives() {
+    assert_error(
+        "piece T\n  rotate 010/111/000",
+        2, 3, ParseErrorKind::UnknownDirective("rotate".to_string()),
+    );
+}
+
+#[test]
+fn reports_unknown_piece_kinds() {
+    assert_error(
+        "piece X",
+        1, 7, ParseErrorKind::UnknownPieceKind("X".to_string()),
+    );
+}
+
+#[test]
+fn reports_duplicate_pieces() {
+    assert_error(
+        "piece O\nstate 11/11\npiece O",
+        3, 7, ParseErrorKind::DuplicatePiece("O".to_string()),
+    );
+}
+
+#[test]
+fn reports_duplicate_kicks() {
+    assert_error(
+        "piece T\nstate 010/111/000\nstate 010/011/010\nkick 0->1 -1,0\nkick 1->0 1,0\n  kick 0->1 0,1",
+        6, 8, ParseErrorKind::DuplicateKick("0->1".to_string()),
+    );
+}
+
+#[test]
+fn reports_directives_outside_pieces() {
+    assert_error("\n  state 11/11", 2, 3, ParseErrorKind::MissingPiece);
+}
+
+#[test]
+fn reports_pieces_without_states() {
+    assert_error("piece O\npiece I\nstate 1111", 1, 1, ParseErrorKind::EmptyPiece);
+    assert_error("piece O\nstate 11/11\n  piece I", 3, 3, ParseErrorKind::EmptyPiece);
+}
+
+#[test]
+fn reports_missing_and_unexpected_arguments() {
+    assert_error("piece", 1, 6, ParseErrorKind::MissingArgument);
+    assert_error("piece O\nstate", 2, 6, ParseErrorKind::MissingArgument);
+    assert_error("piece O\nkick", 2, 5, ParseErrorKind::MissingArgument);
+    assert_error(
+        "piece O\nstate 11/11 11/11",
+        2, 13, ParseErrorKind::UnexpectedArgument("11/11".to_string()),
+    );
+    assert_error(
+        "piece O\ncenter-column yes",
+        2, 15, ParseErrorKind::UnexpectedArgument("yes".to_string()),
+    );
+}
+
+#[test]
+fn reports_invalid_grids_at_the_offending_row() {
+    assert_error(
+        "piece T\nstate 000/121/010",
+        2, 11, ParseErrorKind::InvalidGrid("000/121/010".to_string()),
+    );
+    assert_error(
+        "piece T\nstate 000/11/010",
+        2, 11, ParseErrorKind::InvalidGrid("000/11/010".to_string()),
+    );
+    assert_error(
+        "piece T\nstate 000//010",
+        2, 11, ParseErrorKind::InvalidGrid("000//010".to_string()),
+    );
+}
+
+#[test]
+fn reports_invalid_kicks() {
+    assert_error(
+        "piece T\nkick 0-1 1,0",
+        2, 6, ParseErrorKind::InvalidTransition("0-1".to_string()),
+    );
+    assert_error(
+        "piece T\nkick 0->1 1,0 1;0",
+        2, 15, ParseErrorKind::InvalidOffset("1;0".to_string()),
+    );
+    assert_error(
+        "piece T\nkick 0->1 1,0,2",
+        2, 11, ParseErrorKind::InvalidOffset("1,0,2".to_string()),
+    );
+}
+
+#[test]
+fn formats_errors_with_line_and_column() {
+    let error = parse_rotation_system("piece X").err().unwrap();
+
+    assert_eq!(error.to_string(), "line 1, column 7: unknown piece kind 'X'");
+}
+
+#[test]
+fn loading_validates_the_rotation_system() {
+    let path = env::temp_dir().join("tetris_incomplete_rotation_system.txt");
+    fs::write(&path, "piece I\nstate 0000/1111/0000/0000\n").unwrap();
+
+    let result = load_rotation_system(&path);
+    fs::remove_file(&path).unwrap();
+
+    match result {
+        Err(LoadError::Invalid(errors)) => {
+            assert_eq!(errors.len(), 6);
+            assert!(!errors.contains(&RotationSystemError::MissingPiece(PieceKind::I)));
+        },
+        _ => panic!("Expected the incomplete rotation system to be rejected"),
+    }
+}
+
+#[test]
+fn loading_reports_missing_files() {
+    let path = env::temp_dir().join("tetris_nonexistent_rotation_system.txt");
+
+    match load_rotation_system(&path) {
+        Err(LoadError::Io(_)) => {},
+        _ => panic!("Expected an I/O error"),
+    }
+}
+
+fn assert_error(source: &str, line: usize, column: usize, kind: ParseErrorKind) {
+    match parse_rotation_system(source) {
+        Ok(_) => panic!("Expected a parse error for {:?}", source),
+        Err(error) => assert_eq!(error, ParseError { line, column, kind }),
+    }
+}
+
+fn assert_same_piece(kind: &PieceKind, left: &RotationSystem, right: &RotationSystem) {
+    let left_table = left.get(kind).unwrap();
+    let right_table = right.get(kind).unwrap();
+
+    assert_eq!(left_table.len(), right_table.len(), "{:?}", kind);
+
+    for (left_grid, right_grid) in left_table.iter().zip(right_table.iter()) {
+        assert_eq!(readable_grid(left_grid), readable_grid(right_grid), "{:?}", kind);
+    }
+
+    for from_state in 0..left_table.len() {
+        for to_state in 0..left_table.len() {
+            assert_eq!(
+                left.get_kicks(kind, from_state, to_state),
+                right.get_kicks(kind, from_state, to_state),
+                "{:?} {}->{}", kind, from_state, to_state
+            );
+        }
+    }
+
+    assert_eq!(left.has_center_column_rule(kind), right.has_center_column_rule(kind));
+}
+
+fn readable_grid(grid: &PieceGrid) -> String {
+    let mut result = String::new();
+
+    for &tile in &grid.0 {
+        result += if tile { "1" } else { "0" };
+    }
+
+    result
+}
@@ -1,4 +1,16 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RotationDirection {
     Clockwise,
     Counterclockwise,
+    Half,
+}
+
+impl RotationDirection {
+    pub fn reverse(&self) -> RotationDirection {
+        match self {
+            RotationDirection::Clockwise => RotationDirection::Counterclockwise,
+            RotationDirection::Counterclockwise => RotationDirection::Clockwise,
+            RotationDirection::Half => RotationDirection::Half,
+        }
+    }
 }
@@ -1,5 +1,75 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use super::super::piece::PieceKind;
-use super::RotationTable;
+use super::super::position::BoardPositionOffset;
+use super::{KickTable, RotationTable};
 
-pub type RotationSystem = HashMap<PieceKind, RotationTable>;
+/**
+ * Maps each piece kind to its rotation table and, optionally, to the kick
+ * offsets tried when a rotation between two states collides.
+ *
+ * Piece kinds following the center column rule don't kick if the first
+ * blocked cell of the rotated piece, in reading order, is in the center
+ * column of its grid.
+ */
+pub struct RotationSystem {
+    rotation_tables: HashMap<PieceKind, RotationTable>,
+    kick_tables: HashMap<PieceKind, KickTable>,
+    center_column_kinds: HashSet<PieceKind>,
+}
+
+impl RotationSystem {
+    pub fn new() -> RotationSystem {
+        RotationSystem {
+            rotation_tables: HashMap::new(),
+            kick_tables: HashMap::new(),
+            center_column_kinds: HashSet::new(),
+        }
+    }
+
+    pub fn insert(&mut self, kind: PieceKind, rotation_table: RotationTable) {
+        self.rotation_tables.insert(kind, rotation_table);
+    }
+
+    pub fn insert_kicks(&mut self, kind: PieceKind, kick_table: KickTable) {
+        self.kick_tables.insert(kind, kick_table);
+    }
+
+    pub fn enable_center_column_rule(&mut self, kind: PieceKind) {
+        self.center_column_kinds.insert(kind);
+    }
+
+    pub fn get(&self, kind: &PieceKind) -> Option<&RotationTable> {
+        self.rotation_tables.get(kind)
+    }
+
+    pub fn get_kick_table(&self, kind: &PieceKind) -> Option<&KickTable> {
+        self.kick_tables.get(kind)
+    }
+
+    /**
+     * Returns the offsets to try, in order, when rotating a piece from
+     * one state to another collides. The unkicked rotation is always
+     * tried first and is not part of the returned offsets.
+     */
+    pub fn get_kicks(
+        &self,
+        kind: &PieceKind,
+        from_state: usize,
+        to_state: usize,
+    ) -> &[BoardPositionOffset] {
+        self.kick_tables.get(kind)
+            .and_then(|kick_table| kick_table.get(&(from_state, to_state)))
+            .map(|kicks| kicks.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn has_center_column_rule(&self, kind: &PieceKind) -> bool {
+        self.center_column_kinds.contains(kind)
+    }
+}
+
+impl Default for RotationSystem {
+    fn default() -> RotationSystem {
+        RotationSystem::new()
+    }
+}
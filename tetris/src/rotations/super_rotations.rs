@@ -0,0 +1,113 @@
+use super::super::piece::PieceKind;
+use super::{make_kick_table, make_rotation_table, KickTable, RotationSystem, RotationTable};
+
+/**
+ * Super Rotation System, as described by the Tetris Guideline.
+ * States are numbered 0 (spawn), 1 (R), 2 and 3 (L).
+ */
+pub fn build_super_rotation_system() -> RotationSystem {
+    let mut result = RotationSystem::new();
+
+    result.insert(PieceKind::I, super_rotation_i());
+    result.insert(PieceKind::J, super_rotation_j());
+    result.insert(PieceKind::L, super_rotation_l());
+    result.insert(PieceKind::O, super_rotation_o());
+    result.insert(PieceKind::S, super_rotation_s());
+    result.insert(PieceKind::T, super_rotation_t());
+    result.insert(PieceKind::Z, super_rotation_z());
+
+    result.insert_kicks(PieceKind::I, super_kicks_i());
+    result.insert_kicks(PieceKind::J, super_kicks_jlstz());
+    result.insert_kicks(PieceKind::L, super_kicks_jlstz());
+    result.insert_kicks(PieceKind::S, super_kicks_jlstz());
+    result.insert_kicks(PieceKind::T, super_kicks_jlstz());
+    result.insert_kicks(PieceKind::Z, super_kicks_jlstz());
+
+    result
+}
+
+fn super_rotation_i() -> RotationTable {
+    make_rotation_table(&[
+        &["0000", "1111", "0000", "0000"],
+        &["0010", "0010", "0010", "0010"],
+        &["0000", "0000", "1111", "0000"],
+        &["0100", "0100", "0100", "0100"],
+    ])
+}
+
+fn super_rotation_j() -> RotationTable {
+    make_rotation_table(&[
+        &["100", "111", "000"],
+        &["011", "010", "010"],
+        &["000", "111", "001"],
+        &["010", "010", "110"],
+    ])
+}
+
+fn super_rotation_l() -> RotationTable {
+    make_rotation_table(&[
+        &["001", "111", "000"],
+        &["010", "010", "011"],
+        &["000", "111", "100"],
+        &["110", "010", "010"],
+    ])
+}
+
+fn super_rotation_o() -> RotationTable {
+    make_rotation_table(&[
+        &["11", "11"],
+    ])
+}
+
+fn super_rotation_s() -> RotationTable {
+    make_rotation_table(&[
+        &["011", "110", "000"],
+        &["010", "011", "001"],
+        &["000", "011", "110"],
+        &["100", "110", "010"],
+    ])
+}
+
+fn super_rotation_t() -> RotationTable {
+    make_rotation_table(&[
+        &["010", "111", "000"],
+        &["010", "011", "010"],
+        &["000", "111", "010"],
+        &["010", "110", "010"],
+    ])
+}
+
+fn super_rotation_z() -> RotationTable {
+    make_rotation_table(&[
+        &["110", "011", "000"],
+        &["001", "011", "010"],
+        &["000", "110", "011"],
+        &["010", "110", "100"],
+    ])
+}
+
+fn super_kicks_jlstz() -> KickTable {
+    make_kick_table(&[
+        ((0, 1), &[(-1, 0), (-1, 1), (0, -2), (-1, -2)]),
+        ((1, 0), &[(1, 0), (1, -1), (0, 2), (1, 2)]),
+        ((1, 2), &[(1, 0), (1, -1), (0, 2), (1, 2)]),
+        ((2, 1), &[(-1, 0), (-1, 1), (0, -2), (-1, -2)]),
+        ((2, 3), &[(1, 0), (1, 1), (0, -2), (1, -2)]),
+        ((3, 2), &[(-1, 0), (-1, -1), (0, 2), (-1, 2)]),
+        ((3, 0), &[(-1, 0), (-1, -1), (0, 2), (-1, 2)]),
+        ((0, 3), &[(1, 0), (1, 1), (0, -2), (1, -2)]),
+    ])
+}
+
+fn super_kicks_i() -> KickTable {
+    make_kick_table(&[
+        ((0, 1), &[(-2, 0), (1, 0), (-2, -1), (1, 2)]),
+        ((1, 0), &[(2, 0), (-1, 0), (2, 1), (-1, -2)]),
+        ((1, 2), &[(-1, 0), (2, 0), (-1, 2), (2, -1)]),
+        ((2, 1), &[(1, 0), (-2, 0), (1, -2), (-2, 1)]),
+        ((2, 3), &[(2, 0), (-1, 0), (2, 1), (-1, -2)]),
+        ((3, 2), &[(-2, 0), (1, 0), (-2, -1), (1, 2)]),
+        ((3, 0), &[(1, 0), (-2, 0), (1, -2), (-2, 1)]),
+        ((0, 3), &[(-1, 0), (2, 0), (-1, 2), (2, -1)]),
+    ])
+}
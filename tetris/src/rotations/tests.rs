@@ -1,5 +1,17 @@
+use std::collections::HashSet;
+use super::super::board::{Block, SimpleBoard};
+use super::super::core::ActivePiece;
+use super::super::helpers;
 use super::super::piece::{PieceColor, Piece, PieceGrid, PieceKind};
-use super::{build_nintendo_rotation_system, RotationDirection, RotationSystem};
+use super::super::position::{BoardPosition, BoardPositionOffset};
+use super::{
+    build_arika_rotation_system,
+    build_nintendo_rotation_system,
+    build_super_rotation_system,
+    make_kick_table,
+    RotationDirection,
+    RotationSystem,
+};
 
 #[test]
 fn nintendo_rotation_i() {
@@ -8,10 +20,10 @@ fn nintendo_rotation_i() {
     let mut piece = new_piece(PieceKind::I, 0);
     assert_grid_eq(&piece, &rotation_system, "0000/0000/1111/0000");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "0010/0010/0010/0010");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "0000/0000/1111/0000");
 }
 
@@ -22,16 +34,16 @@ fn nintendo_rotation_j() {
     let mut piece = new_piece(PieceKind::J, 0);
     assert_grid_eq(&piece, &rotation_system, "000/111/001");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "010/010/110");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "100/111/000");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "011/010/010");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "000/111/001");
 }
 
@@ -42,16 +54,16 @@ fn nintendo_rotation_l() {
     let mut piece = new_piece(PieceKind::L, 0);
     assert_grid_eq(&piece, &rotation_system, "000/111/100");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "110/010/010");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "001/111/000");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "010/010/011");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "000/111/100");
 }
 
@@ -62,7 +74,7 @@ fn nintendo_rotation_o() {
     let mut piece = new_piece(PieceKind::O, 0);
     assert_grid_eq(&piece, &rotation_system, "11/11");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "11/11");
 }
 
@@ -73,10 +85,10 @@ fn nintendo_rotation_s() {
     let mut piece = new_piece(PieceKind::S, 0);
     assert_grid_eq(&piece, &rotation_system, "000/011/110");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "010/011/001");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "000/011/110");
 }
 
@@ -87,16 +99,16 @@ fn nintendo_rotation_t() {
     let mut piece = new_piece(PieceKind::T, 0);
     assert_grid_eq(&piece, &rotation_system, "000/111/010");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "010/110/010");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "010/111/000");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "010/011/010");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "000/111/010");
 }
 
@@ -107,10 +119,10 @@ fn nintendo_rotation_z() {
     let mut piece = new_piece(PieceKind::Z, 0);
     assert_grid_eq(&piece, &rotation_system, "000/110/011");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "001/011/010");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "000/110/011");
 }
 
@@ -121,19 +133,565 @@ fn nintendo_rotation_t_different_initial_state() {
     let mut piece = new_piece(PieceKind::T, 1);
     assert_grid_eq(&piece, &rotation_system, "010/110/010");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "010/111/000");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "010/011/010");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "000/111/010");
 
-    piece.rotate(RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
     assert_grid_eq(&piece, &rotation_system, "010/110/010");
 }
 
+#[test]
+fn super_rotation_i() {
+    let rotation_system = build_super_rotation_system();
+
+    let mut piece = new_piece(PieceKind::I, 0);
+    assert_grid_eq(&piece, &rotation_system, "0000/1111/0000/0000");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "0010/0010/0010/0010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "0000/0000/1111/0000");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "0100/0100/0100/0100");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "0000/1111/0000/0000");
+}
+
+#[test]
+fn super_rotation_j() {
+    let rotation_system = build_super_rotation_system();
+
+    let mut piece = new_piece(PieceKind::J, 0);
+    assert_grid_eq(&piece, &rotation_system, "100/111/000");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "011/010/010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "000/111/001");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "010/010/110");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "100/111/000");
+}
+
+#[test]
+fn super_rotation_l() {
+    let rotation_system = build_super_rotation_system();
+
+    let mut piece = new_piece(PieceKind::L, 0);
+    assert_grid_eq(&piece, &rotation_system, "001/111/000");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "010/010/011");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "000/111/100");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "110/010/010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "001/111/000");
+}
+
+#[test]
+fn super_rotation_o() {
+    let rotation_system = build_super_rotation_system();
+
+    let mut piece = new_piece(PieceKind::O, 0);
+    assert_grid_eq(&piece, &rotation_system, "11/11");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "11/11");
+}
+
+#[test]
+fn super_rotation_s() {
+    let rotation_system = build_super_rotation_system();
+
+    let mut piece = new_piece(PieceKind::S, 0);
+    assert_grid_eq(&piece, &rotation_system, "011/110/000");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "010/011/001");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "000/011/110");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "100/110/010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "011/110/000");
+}
+
+#[test]
+fn super_rotation_t() {
+    let rotation_system = build_super_rotation_system();
+
+    let mut piece = new_piece(PieceKind::T, 0);
+    assert_grid_eq(&piece, &rotation_system, "010/111/000");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "010/011/010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "000/111/010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "010/110/010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "010/111/000");
+}
+
+#[test]
+fn super_rotation_z() {
+    let rotation_system = build_super_rotation_system();
+
+    let mut piece = new_piece(PieceKind::Z, 0);
+    assert_grid_eq(&piece, &rotation_system, "110/011/000");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "001/011/010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "000/110/011");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "010/110/100");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "110/011/000");
+}
+
+#[test]
+fn super_rotation_kick_tables() {
+    let rotation_system = build_super_rotation_system();
+
+    assert_eq!(
+        rotation_system.get_kicks(&PieceKind::T, 0, 1),
+        offsets(&[(0, -1), (-1, -1), (2, 0), (2, -1)]).as_slice()
+    );
+    assert_eq!(
+        rotation_system.get_kicks(&PieceKind::I, 0, 1),
+        offsets(&[(0, -2), (0, 1), (1, -2), (-2, 1)]).as_slice()
+    );
+    assert!(rotation_system.get_kicks(&PieceKind::O, 0, 0).is_empty());
+    assert!(rotation_system.get_kicks(&PieceKind::T, 0, 2).is_empty());
+}
+
+#[test]
+fn super_rotation_uses_every_kick_in_order() {
+    let kinds = [
+        PieceKind::I,
+        PieceKind::J,
+        PieceKind::L,
+        PieceKind::S,
+        PieceKind::T,
+        PieceKind::Z,
+    ];
+
+    let transitions = [
+        (0, 1), (1, 0), (1, 2), (2, 1),
+        (2, 3), (3, 2), (3, 0), (0, 3),
+    ];
+
+    for kind in kinds.iter() {
+        for &(from_state, to_state) in transitions.iter() {
+            for test_index in 0..5 {
+                assert_kick_used(*kind, from_state, to_state, test_index);
+            }
+        }
+    }
+}
+
+#[test]
+fn super_rotation_fails_when_every_kick_collides() {
+    let rotation_system = build_super_rotation_system();
+    let origin = BoardPosition::new(4, 4);
+    let tests = get_kick_tests(&rotation_system, PieceKind::T, 0, 1, origin);
+    let mut board = SimpleBoard::new(12, 12);
+
+    for test_position in &tests {
+        for cell in get_cells(&rotation_system, PieceKind::T, 1, *test_position) {
+            board.put_block(&cell, Block { color: PieceColor::Blue });
+        }
+    }
+
+    let mut active_piece = ActivePiece::new(new_piece(PieceKind::T, 0), origin, rotation_system);
+    active_piece.try_rotate(RotationDirection::Clockwise, &board);
+
+    assert_eq!(active_piece.get_piece().get_rotation_index(), 0);
+    assert_eq!(*active_piece.get_position(), origin);
+}
+
+#[test]
+fn arika_rotation_t() {
+    let rotation_system = build_arika_rotation_system();
+
+    let mut piece = new_piece(PieceKind::T, 0);
+    assert_grid_eq(&piece, &rotation_system, "000/111/010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "010/110/010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "000/010/111");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "010/011/010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "000/111/010");
+}
+
+#[test]
+fn arika_rotation_s_and_z() {
+    let rotation_system = build_arika_rotation_system();
+
+    let mut piece = new_piece(PieceKind::S, 0);
+    assert_grid_eq(&piece, &rotation_system, "000/011/110");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "100/110/010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "000/011/110");
+
+    let mut piece = new_piece(PieceKind::Z, 0);
+    assert_grid_eq(&piece, &rotation_system, "000/110/011");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "001/011/010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "000/110/011");
+}
+
+#[test]
+fn arika_rotation_j_and_l() {
+    let rotation_system = build_arika_rotation_system();
+
+    let mut piece = new_piece(PieceKind::J, 0);
+    assert_grid_eq(&piece, &rotation_system, "000/111/001");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "010/010/110");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "000/100/111");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "011/010/010");
+
+    let mut piece = new_piece(PieceKind::L, 0);
+    assert_grid_eq(&piece, &rotation_system, "000/111/100");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "110/010/010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "000/001/111");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "010/010/011");
+}
+
+#[test]
+fn arika_rotation_kicks_right_first() {
+    let board = SimpleBoard::new(6, 6);
+    let mut active_piece = ActivePiece::new(
+        new_piece(PieceKind::T, 3),
+        BoardPosition::new(2, -1),
+        build_arika_rotation_system(),
+    );
+
+    active_piece.try_rotate(RotationDirection::Clockwise, &board);
+
+    assert_eq!(active_piece.get_piece().get_rotation_index(), 0);
+    assert_eq!(*active_piece.get_position(), BoardPosition::new(2, 0));
+}
+
+#[test]
+fn arika_rotation_kicks_left_when_right_fails() {
+    let board = SimpleBoard::new(6, 6);
+    let mut active_piece = ActivePiece::new(
+        new_piece(PieceKind::J, 1),
+        BoardPosition::new(2, 4),
+        build_arika_rotation_system(),
+    );
+
+    active_piece.try_rotate(RotationDirection::Clockwise, &board);
+
+    assert_eq!(active_piece.get_piece().get_rotation_index(), 2);
+    assert_eq!(*active_piece.get_position(), BoardPosition::new(2, 3));
+}
+
+#[test]
+fn arika_rotation_center_column_rule_prevents_kicks() {
+    let board = SimpleBoard::from_array(&[
+        "000000",
+        "000000",
+        "001000",
+        "000000",
+        "000000",
+        "000000",
+    ]);
+
+    let mut active_piece = ActivePiece::new(
+        new_piece(PieceKind::T, 0),
+        BoardPosition::new(2, 1),
+        build_arika_rotation_system(),
+    );
+
+    active_piece.try_rotate(RotationDirection::Clockwise, &board);
+
+    assert_eq!(active_piece.get_piece().get_rotation_index(), 0);
+    assert_eq!(*active_piece.get_position(), BoardPosition::new(2, 1));
+}
+
+#[test]
+fn arika_rotation_center_column_rule_uses_first_blocked_cell() {
+    let board = SimpleBoard::from_array(&[
+        "000000",
+        "000000",
+        "000000",
+        "010000",
+        "001000",
+        "000000",
+    ]);
+
+    let mut active_piece = ActivePiece::new(
+        new_piece(PieceKind::T, 0),
+        BoardPosition::new(2, 1),
+        build_arika_rotation_system(),
+    );
+
+    active_piece.try_rotate(RotationDirection::Clockwise, &board);
+
+    assert_eq!(active_piece.get_piece().get_rotation_index(), 1);
+    assert_eq!(*active_piece.get_position(), BoardPosition::new(2, 2));
+}
+
+#[test]
+fn arika_rotation_center_column_rule_only_applies_to_l_j_t() {
+    let board = SimpleBoard::from_array(&[
+        "000000",
+        "000000",
+        "000000",
+        "000000",
+        "010000",
+        "000000",
+    ]);
+
+    let mut active_piece = ActivePiece::new(
+        new_piece(PieceKind::S, 0),
+        BoardPosition::new(2, 0),
+        build_arika_rotation_system(),
+    );
+
+    active_piece.try_rotate(RotationDirection::Clockwise, &board);
+
+    assert_eq!(active_piece.get_piece().get_rotation_index(), 1);
+    assert_eq!(*active_piece.get_position(), BoardPosition::new(2, 1));
+}
+
+#[test]
+fn arika_rotation_i_does_not_kick() {
+    let board = SimpleBoard::new(6, 6);
+    let mut active_piece = ActivePiece::new(
+        new_piece(PieceKind::I, 1),
+        BoardPosition::new(1, 3),
+        build_arika_rotation_system(),
+    );
+
+    active_piece.try_rotate(RotationDirection::Clockwise, &board);
+
+    assert_eq!(active_piece.get_piece().get_rotation_index(), 1);
+    assert_eq!(*active_piece.get_position(), BoardPosition::new(1, 3));
+}
+
+#[test]
+fn half_rotation_four_states() {
+    let rotation_system = build_super_rotation_system();
+
+    let mut piece = new_piece(PieceKind::T, 0);
+    piece.rotate(&RotationDirection::Half, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "000/111/010");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Half, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "010/011/010");
+
+    piece.rotate(&RotationDirection::Half, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "010/110/010");
+}
+
+#[test]
+fn half_rotation_two_states() {
+    let rotation_system = build_nintendo_rotation_system();
+
+    let mut piece = new_piece(PieceKind::S, 0);
+    piece.rotate(&RotationDirection::Half, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "000/011/110");
+
+    piece.rotate(&RotationDirection::Clockwise, &rotation_system);
+    piece.rotate(&RotationDirection::Half, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "010/011/001");
+}
+
+#[test]
+fn half_rotation_one_state() {
+    let rotation_system = build_nintendo_rotation_system();
+
+    let mut piece = new_piece(PieceKind::O, 0);
+    piece.rotate(&RotationDirection::Half, &rotation_system);
+    assert_grid_eq(&piece, &rotation_system, "11/11");
+}
+
+#[test]
+fn half_rotation_without_kicks_reverts_on_collision() {
+    let board = SimpleBoard::from_array(&[
+        "000000",
+        "000000",
+        "000000",
+        "000000",
+        "001000",
+        "000000",
+    ]);
+
+    let mut active_piece = ActivePiece::new(
+        new_piece(PieceKind::T, 0),
+        BoardPosition::new(2, 1),
+        build_super_rotation_system(),
+    );
+
+    active_piece.try_rotate(RotationDirection::Half, &board);
+
+    assert_eq!(active_piece.get_piece().get_rotation_index(), 0);
+    assert_eq!(*active_piece.get_position(), BoardPosition::new(2, 1));
+}
+
+#[test]
+fn half_rotation_uses_its_own_kicks() {
+    let board = SimpleBoard::from_array(&[
+        "000000",
+        "000000",
+        "000000",
+        "000000",
+        "001000",
+        "000000",
+    ]);
+
+    let mut rotation_system = build_super_rotation_system();
+    rotation_system.insert_kicks(PieceKind::T, make_kick_table(&[
+        ((0, 2), &[(0, 1)]),
+    ]));
+
+    let mut active_piece = ActivePiece::new(
+        new_piece(PieceKind::T, 0),
+        BoardPosition::new(2, 1),
+        rotation_system,
+    );
+
+    active_piece.try_rotate(RotationDirection::Half, &board);
+
+    assert_eq!(active_piece.get_piece().get_rotation_index(), 2);
+    assert_eq!(*active_piece.get_position(), BoardPosition::new(1, 1));
+}
+
+fn assert_kick_used(kind: PieceKind, from_state: usize, to_state: usize, test_index: usize) {
+    let rotation_system = build_super_rotation_system();
+    let origin = BoardPosition::new(4, 4);
+    let tests = get_kick_tests(&rotation_system, kind, from_state, to_state, origin);
+    let initial_cells = get_cells(&rotation_system, kind, from_state, origin);
+    let target_cells = get_cells(&rotation_system, kind, to_state, tests[test_index]);
+    let mut board = SimpleBoard::new(12, 12);
+
+    // Blocks every earlier test without touching the expected one. Cells
+    // covered by the piece before rotating are only used as a last resort,
+    // since try_rotate never checks them.
+    for earlier_test in &tests[..test_index] {
+        let candidates: Vec<_> = get_cells(&rotation_system, kind, to_state, *earlier_test)
+            .into_iter()
+            .filter(|cell| !target_cells.contains(cell))
+            .collect();
+
+        let blocking_cell = candidates.iter()
+            .find(|cell| !initial_cells.contains(cell))
+            .or_else(|| candidates.first())
+            .cloned()
+            .expect("Kick test cannot be isolated");
+
+        board.put_block(&blocking_cell, Block { color: PieceColor::Blue });
+    }
+
+    let direction = if to_state == (from_state + 1) % 4 {
+        RotationDirection::Clockwise
+    } else {
+        RotationDirection::Counterclockwise
+    };
+
+    let mut active_piece = ActivePiece::new(new_piece(kind, from_state), origin, rotation_system);
+    active_piece.try_rotate(direction, &board);
+
+    assert_eq!(
+        active_piece.get_piece().get_rotation_index(),
+        to_state,
+        "{:?} {}->{} test {}", kind, from_state, to_state, test_index
+    );
+    assert_eq!(
+        *active_piece.get_position(),
+        tests[test_index],
+        "{:?} {}->{} test {}", kind, from_state, to_state, test_index
+    );
+}
+
+fn get_kick_tests(
+    rotation_system: &RotationSystem,
+    kind: PieceKind,
+    from_state: usize,
+    to_state: usize,
+    origin: BoardPosition,
+) -> Vec<BoardPosition> {
+    let kicks = rotation_system.get_kicks(&kind, from_state, to_state);
+    let mut result = vec![origin];
+
+    result.extend(kicks.iter().map(|kick| origin + kick));
+
+    result
+}
+
+fn get_cells(
+    rotation_system: &RotationSystem,
+    kind: PieceKind,
+    state: usize,
+    position: BoardPosition,
+) -> HashSet<BoardPosition> {
+    let piece = new_piece(kind, state);
+
+    helpers::get_piece_iterator(&piece, &position, rotation_system).collect()
+}
+
+fn offsets(values: &[(isize, isize)]) -> Vec<BoardPositionOffset> {
+    values.iter()
+        .map(|&(row, column)| BoardPositionOffset::new(row, column))
+        .collect()
+}
+
 fn new_piece(kind: PieceKind, rotation_index: usize) -> Piece {
     Piece::new(kind, PieceColor::Blue, rotation_index)
 }
@@ -0,0 +1,8 @@
+mod rotation_system_error;
+mod validate;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::rotation_system_error::RotationSystemError;
+pub use self::validate::validate;
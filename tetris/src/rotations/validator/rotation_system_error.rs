@@ -0,0 +1,67 @@
+use std::fmt;
+use super::super::super::piece::PieceKind;
+
+#[derive(Debug, PartialEq)]
+pub enum RotationSystemError {
+    MissingPiece(PieceKind),
+    EmptyRotationTable(PieceKind),
+    NonSquareGrid {
+        kind: PieceKind,
+        state: usize,
+    },
+    EmptyGrid {
+        kind: PieceKind,
+        state: usize,
+    },
+    InconsistentCellCount {
+        kind: PieceKind,
+        state: usize,
+        expected: usize,
+        found: usize,
+    },
+    DisconnectedGrid {
+        kind: PieceKind,
+        state: usize,
+    },
+    InvalidKickTransition {
+        kind: PieceKind,
+        from_state: usize,
+        to_state: usize,
+    },
+}
+
+impl fmt::Display for RotationSystemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RotationSystemError::MissingPiece(kind) => {
+                write!(f, "piece {:?} is missing", kind)
+            },
+            RotationSystemError::EmptyRotationTable(kind) => {
+                write!(f, "piece {:?} has no states", kind)
+            },
+            RotationSystemError::NonSquareGrid { kind, state } => {
+                write!(f, "state {} of piece {:?} is not a square grid", state, kind)
+            },
+            RotationSystemError::EmptyGrid { kind, state } => {
+                write!(f, "state {} of piece {:?} has no blocks", state, kind)
+            },
+            RotationSystemError::InconsistentCellCount { kind, state, expected, found } => {
+                write!(
+                    f,
+                    "state {} of piece {:?} has {} blocks, expected {}",
+                    state, kind, found, expected
+                )
+            },
+            RotationSystemError::DisconnectedGrid { kind, state } => {
+                write!(f, "state {} of piece {:?} is not connected", state, kind)
+            },
+            RotationSystemError::InvalidKickTransition { kind, from_state, to_state } => {
+                write!(
+                    f,
+                    "piece {:?} has kicks for nonexistent transition {}->{}",
+                    kind, from_state, to_state
+                )
+            },
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use super::super::super::piece::{PieceKind, ALL_PIECE_KINDS};
+use super::super::{
+    build_arika_rotation_system,
+    build_nintendo_rotation_system,
+    build_super_rotation_system,
+    make_kick_table,
+    make_rotation_table,
+    RotationSystem,
+};
+use super::{validate, RotationSystemError};
+
+#[test]
+fn builtin_rotation_systems_are_valid() {
+    assert_eq!(validate(&build_nintendo_rotation_system()), Ok(()));
+    assert_eq!(validate(&build_super_rotation_system()), Ok(()));
+    assert_eq!(validate(&build_arika_rotation_system()), Ok(()));
+}
+
+#[test]
+fn reports_every_missing_piece() {
+    let mut rotation_system = RotationSystem::new();
+    rotation_system.insert(PieceKind::O, make_rotation_table(&[&["11", "11"]]));
+
+    let expected: Vec<_> = ALL_PIECE_KINDS.iter()
+        .filter(|kind| **kind != PieceKind::O)
+        .map(|kind| RotationSystemError::MissingPiece(*kind))
+        .collect();
+
+    assert_eq!(validate(&rotation_system), Err(expected));
+}
+
+#[test]
+fn reports_empty_rotation_tables() {
+    let mut rotation_system = build_nintendo_rotation_system();
+    rotation_system.insert(PieceKind::T, Vec::new());
+
+    assert_eq!(
+        validate(&rotation_system),
+        Err(vec![RotationSystemError::EmptyRotationTable(PieceKind::T)])
+    );
+}
+
+#[test]
+fn reports_non_square_grids() {
+    let mut rotation_system = build_nintendo_rotation_system();
+    rotation_system.insert(PieceKind::I, make_rotation_table(&[
+        &["0010", "0010", "0010", "0010"],
+        &["1111", "00"],
+    ]));
+
+    assert_eq!(
+        validate(&rotation_system),
+        Err(vec![RotationSystemError::NonSquareGrid { kind: PieceKind::I, state: 1 }])
+    );
+}
+
+#[test]
+fn reports_empty_grids() {
+    let mut rotation_system = build_nintendo_rotation_system();
+    rotation_system.insert(PieceKind::O, make_rotation_table(&[&["00", "00"]]));
+
+    assert_eq!(
+        validate(&rotation_system),
+        Err(vec![RotationSystemError::EmptyGrid { kind: PieceKind::O, state: 0 }])
+    );
+}
+
+#[test]
+fn reports_inconsistent_cell_counts() {
+    let mut rotation_system = build_nintendo_rotation_system();
+    rotation_system.insert(PieceKind::T, make_rotation_table(&[
+        &["010", "111", "000"],
+        &["010", "011", "000"],
+    ]));
+
+    assert_eq!(
+        validate(&rotation_system),
+        Err(vec![RotationSystemError::InconsistentCellCount {
+            kind: PieceKind::T,
+            state: 1,
+            expected: 4,
+            found: 3,
+        }])
+    );
+}
+
+#[test]
+fn reports_disconnected_grids() {
+    let mut rotation_system = build_nintendo_rotation_system();
+    rotation_system.insert(PieceKind::S, make_rotation_table(&[
+        &["011", "110", "000"],
+        &["101", "100", "010"],
+    ]));
+
+    assert_eq!(
+        validate(&rotation_system),
+        Err(vec![RotationSystemError::DisconnectedGrid { kind: PieceKind::S, state: 1 }])
+    );
+}
+
+#[test]
+fn reports_kicks_for_nonexistent_states() {
+    let mut rotation_system = build_super_rotation_system();
+    rotation_system.insert_kicks(PieceKind::O, make_kick_table(&[
+        ((0, 1), &[(1, 0)]),
+    ]));
+
+    assert_eq!(
+        validate(&rotation_system),
+        Err(vec![RotationSystemError::InvalidKickTransition {
+            kind: PieceKind::O,
+            from_state: 0,
+            to_state: 1,
+        }])
+    );
+}
+
+#[test]
+fn reports_every_problem_at_once() {
+    let mut rotation_system = build_nintendo_rotation_system();
+    rotation_system.insert(PieceKind::J, make_rotation_table(&[
+        &["100", "111", "000"],
+        &["101", "010", "010"],
+        &["1", "11"],
+        &["11", "00"],
+    ]));
+
+    assert_eq!(
+        validate(&rotation_system),
+        Err(vec![
+            RotationSystemError::DisconnectedGrid { kind: PieceKind::J, state: 1 },
+            RotationSystemError::NonSquareGrid { kind: PieceKind::J, state: 2 },
+            RotationSystemError::InconsistentCellCount {
+                kind: PieceKind::J,
+                state: 3,
+                expected: 4,
+                found: 2,
+            },
+        ])
+    );
+}
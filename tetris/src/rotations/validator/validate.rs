@@ -0,0 +1,155 @@
+use super::super::super::piece::{PieceGrid, PieceKind, ALL_PIECE_KINDS};
+use super::super::super::position::PiecePosition;
+use super::super::{RotationSystem, RotationTable};
+use super::RotationSystemError;
+
+/**
+ * Checks that a rotation system can be used by the game: every piece kind
+ * must be present, and every state of a piece must be a square grid with
+ * the same number of connected blocks. Returns every problem found.
+ */
+pub fn validate(rotation_system: &RotationSystem) -> Result<(), Vec<RotationSystemError>> {
+    let mut errors = Vec::new();
+
+    for kind in ALL_PIECE_KINDS.iter() {
+        match rotation_system.get(kind) {
+            Some(rotation_table) => {
+                validate_rotation_table(*kind, rotation_table, &mut errors);
+                validate_kicks(*kind, rotation_system, rotation_table.len(), &mut errors);
+            },
+            None => errors.push(RotationSystemError::MissingPiece(*kind)),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn validate_rotation_table(
+    kind: PieceKind,
+    rotation_table: &RotationTable,
+    errors: &mut Vec<RotationSystemError>,
+) {
+    if rotation_table.is_empty() {
+        errors.push(RotationSystemError::EmptyRotationTable(kind));
+        return;
+    }
+
+    let expected_cell_count = count_cells(&rotation_table[0]);
+
+    for (state, grid) in rotation_table.iter().enumerate() {
+        let num_columns = match get_grid_num_columns(grid) {
+            Some(num_columns) => num_columns,
+            None => {
+                errors.push(RotationSystemError::NonSquareGrid { kind, state });
+                continue;
+            }
+        };
+
+        let cell_count = count_cells(grid);
+
+        if cell_count == 0 {
+            errors.push(RotationSystemError::EmptyGrid { kind, state });
+            continue;
+        }
+
+        if cell_count != expected_cell_count {
+            errors.push(RotationSystemError::InconsistentCellCount {
+                kind,
+                state,
+                expected: expected_cell_count,
+                found: cell_count,
+            });
+        }
+
+        if !is_connected(grid, num_columns) {
+            errors.push(RotationSystemError::DisconnectedGrid { kind, state });
+        }
+    }
+}
+
+fn validate_kicks(
+    kind: PieceKind,
+    rotation_system: &RotationSystem,
+    num_states: usize,
+    errors: &mut Vec<RotationSystemError>,
+) {
+    let kick_table = match rotation_system.get_kick_table(&kind) {
+        Some(kick_table) => kick_table,
+        None => return,
+    };
+
+    let mut transitions: Vec<_> = kick_table.keys()
+        .filter(|(from_state, to_state)| *from_state >= num_states || *to_state >= num_states)
+        .collect();
+
+    transitions.sort();
+
+    for &(from_state, to_state) in transitions {
+        errors.push(RotationSystemError::InvalidKickTransition { kind, from_state, to_state });
+    }
+}
+
+fn get_grid_num_columns(grid: &PieceGrid) -> Option<usize> {
+    let grid_size = grid.0.len();
+    let num_columns = (grid_size as f32).sqrt() as usize;
+
+    if grid_size > 0 && num_columns * num_columns == grid_size {
+        Some(num_columns)
+    } else {
+        None
+    }
+}
+
+fn count_cells(grid: &PieceGrid) -> usize {
+    grid.0.iter().filter(|tile| **tile).count()
+}
+
+fn is_connected(grid: &PieceGrid, num_columns: usize) -> bool {
+    let start = match grid.0.iter().position(|tile| *tile) {
+        Some(index) => index,
+        None => return true,
+    };
+
+    let mut visited = vec![false; grid.0.len()];
+    let mut pending = vec![start];
+    visited[start] = true;
+
+    while let Some(index) = pending.pop() {
+        let position = PiecePosition::from_index(index, num_columns);
+        let (row, column) = (position.get_row(), position.get_column());
+        let mut neighbors = Vec::new();
+
+        if row > 0 {
+            neighbors.push(PiecePosition::new(row - 1, column));
+        }
+
+        if row + 1 < num_columns {
+            neighbors.push(PiecePosition::new(row + 1, column));
+        }
+
+        if column > 0 {
+            neighbors.push(PiecePosition::new(row, column - 1));
+        }
+
+        if column + 1 < num_columns {
+            neighbors.push(PiecePosition::new(row, column + 1));
+        }
+
+        for neighbor in neighbors {
+            let neighbor_index = neighbor.to_index(num_columns);
+
+            if grid.0[neighbor_index] && !visited[neighbor_index] {
+                visited[neighbor_index] = true;
+                pending.push(neighbor_index);
+            }
+        }
+    }
+
+    grid.0.iter()
+        .zip(visited.iter())
+        .all(|(tile, was_visited)| !tile || *was_visited)
+}
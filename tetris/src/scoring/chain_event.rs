@@ -0,0 +1,11 @@
+/**
+ * Rows completed by blocks falling after a clear, with gravity modes that
+ * chain clears.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainEvent {
+    pub level: usize,
+    // Position in the chain: 1 for the first clear caused by falling blocks
+    pub chain: usize,
+    pub num_cleared_rows: usize,
+}
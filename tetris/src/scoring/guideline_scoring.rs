@@ -0,0 +1,75 @@
+use super::{ChainEvent, LockEvent, Scoring, Spin};
+
+const LINE_CLEAR_POINTS: [u64; 5] = [0, 100, 300, 500, 800];
+const MINI_SPIN_POINTS: [u64; 3] = [100, 200, 400];
+const FULL_SPIN_POINTS: [u64; 4] = [400, 800, 1200, 1600];
+const COMBO_POINTS: u64 = 50;
+const SOFT_DROP_POINTS_PER_ROW: u64 = 1;
+const HARD_DROP_POINTS_PER_ROW: u64 = 2;
+
+/**
+ * Scoring of the guideline. Clears and spins pay a base amount times the
+ * level, back-to-back difficult clears pay half more, consecutive clears
+ * add a combo bonus and drops pay per row. Chain clears pay like line
+ * clears, times one more than their chain position.
+ */
+pub struct GuidelineScoring {
+    score: u64,
+}
+
+impl GuidelineScoring {
+    pub fn new() -> GuidelineScoring {
+        GuidelineScoring { score: 0 }
+    }
+}
+
+impl Default for GuidelineScoring {
+    fn default() -> GuidelineScoring {
+        GuidelineScoring::new()
+    }
+}
+
+impl Scoring for GuidelineScoring {
+    fn on_lock(&mut self, event: &LockEvent) {
+        let level = event.level.max(1) as u64;
+        let mut action_points = get_base_points(event) * level;
+
+        if event.is_back_to_back && event.is_difficult_clear() {
+            action_points += action_points / 2;
+        }
+
+        let combo_points = if event.num_cleared_rows > 0 {
+            COMBO_POINTS * event.combo as u64 * level
+        } else {
+            0
+        };
+
+        let drop_points = event.soft_drop_distance as u64 * SOFT_DROP_POINTS_PER_ROW
+            + event.hard_drop_distance as u64 * HARD_DROP_POINTS_PER_ROW;
+
+        self.score += action_points + combo_points + drop_points;
+    }
+
+    fn on_chain_clear(&mut self, event: &ChainEvent) {
+        let level = event.level.max(1) as u64;
+        let num_cleared_rows = event.num_cleared_rows.min(LINE_CLEAR_POINTS.len() - 1);
+
+        self.score += LINE_CLEAR_POINTS[num_cleared_rows] * level * (event.chain as u64 + 1);
+    }
+
+    fn get_score(&self) -> u64 {
+        self.score
+    }
+}
+
+fn get_base_points(event: &LockEvent) -> u64 {
+    let table: &[u64] = match event.spin {
+        Spin::None => &LINE_CLEAR_POINTS,
+        Spin::Mini => &MINI_SPIN_POINTS,
+        Spin::Full => &FULL_SPIN_POINTS,
+    };
+
+    let index = event.num_cleared_rows.min(table.len() - 1);
+
+    table[index]
+}
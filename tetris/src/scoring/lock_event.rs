@@ -0,0 +1,29 @@
+use super::super::piece::PieceKind;
+use super::Spin;
+
+/**
+ * Everything a scoring system needs to know about a piece locking and the
+ * rows it completed.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockEvent {
+    pub piece_kind: PieceKind,
+    pub level: usize,
+    pub num_cleared_rows: usize,
+    pub soft_drop_distance: usize,
+    pub hard_drop_distance: usize,
+    pub spin: Spin,
+    // Line-clearing locks right before this one, if this one clears rows too
+    pub combo: usize,
+    // Whether this is a difficult clear (tetris or spin) following another
+    pub is_back_to_back: bool,
+}
+
+impl LockEvent {
+    /**
+     * Clears that keep a back-to-back chain going.
+     */
+    pub fn is_difficult_clear(&self) -> bool {
+        self.num_cleared_rows >= 4 || (self.num_cleared_rows > 0 && self.spin != Spin::None)
+    }
+}
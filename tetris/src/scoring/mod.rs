@@ -0,0 +1,19 @@
+mod chain_event;
+mod guideline_scoring;
+mod lock_event;
+mod nes_scoring;
+#[allow(clippy::module_inception)]
+mod scoring;
+mod scoring_kind;
+mod spin;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::chain_event::ChainEvent;
+pub use self::guideline_scoring::GuidelineScoring;
+pub use self::lock_event::LockEvent;
+pub use self::nes_scoring::NesScoring;
+pub use self::scoring::Scoring;
+pub use self::scoring_kind::ScoringKind;
+pub use self::spin::Spin;
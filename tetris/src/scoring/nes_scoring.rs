@@ -0,0 +1,45 @@
+use super::{ChainEvent, LockEvent, Scoring};
+
+const LINE_CLEAR_POINTS: [u64; 5] = [0, 40, 100, 300, 1200];
+
+/**
+ * Scoring of the NES version: line clears pay 40, 100, 300 or 1200 points
+ * times the level plus one, and every row fallen while pushing down pays a
+ * point. Spins, combos and back-to-back clears are not rewarded. Chain
+ * clears pay like line clears, times one more than their chain position.
+ */
+pub struct NesScoring {
+    score: u64,
+}
+
+impl NesScoring {
+    pub fn new() -> NesScoring {
+        NesScoring { score: 0 }
+    }
+}
+
+impl Default for NesScoring {
+    fn default() -> NesScoring {
+        NesScoring::new()
+    }
+}
+
+impl Scoring for NesScoring {
+    fn on_lock(&mut self, event: &LockEvent) {
+        let num_cleared_rows = event.num_cleared_rows.min(LINE_CLEAR_POINTS.len() - 1);
+        let line_clear_points = LINE_CLEAR_POINTS[num_cleared_rows] * (event.level as u64 + 1);
+
+        self.score += line_clear_points + event.soft_drop_distance as u64;
+    }
+
+    fn on_chain_clear(&mut self, event: &ChainEvent) {
+        let num_cleared_rows = event.num_cleared_rows.min(LINE_CLEAR_POINTS.len() - 1);
+        let line_clear_points = LINE_CLEAR_POINTS[num_cleared_rows] * (event.level as u64 + 1);
+
+        self.score += line_clear_points * (event.chain as u64 + 1);
+    }
+
+    fn get_score(&self) -> u64 {
+        self.score
+    }
+}
@@ -0,0 +1,11 @@
+use super::{ChainEvent, LockEvent};
+
+/**
+ * Turns lock events, and the chain clears that may follow them, into
+ * points.
+ */
+pub trait Scoring {
+    fn on_lock(&mut self, event: &LockEvent);
+    fn on_chain_clear(&mut self, event: &ChainEvent);
+    fn get_score(&self) -> u64;
+}
@@ -0,0 +1,4 @@
+pub enum ScoringKind {
+    Nes,
+    Guideline,
+}
@@ -0,0 +1,9 @@
+/**
+ * How a piece was spun into its final position, if at all.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Spin {
+    None,
+    Mini,
+    Full,
+}
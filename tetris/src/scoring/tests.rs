@@ -0,0 +1,161 @@
+use super::super::piece::PieceKind;
+use super::{ChainEvent, GuidelineScoring, LockEvent, NesScoring, Scoring, Spin};
+
+fn make_event(level: usize, num_cleared_rows: usize) -> LockEvent {
+    LockEvent {
+        piece_kind: PieceKind::T,
+        level,
+        num_cleared_rows,
+        soft_drop_distance: 0,
+        hard_drop_distance: 0,
+        spin: Spin::None,
+        combo: 0,
+        is_back_to_back: false,
+    }
+}
+
+fn score_of(scoring: &mut dyn Scoring, events: &[LockEvent]) -> u64 {
+    for event in events {
+        scoring.on_lock(event);
+    }
+
+    scoring.get_score()
+}
+
+#[test]
+fn nes_line_clears_are_multiplied_by_the_next_level() {
+    let cases = [(0, 0, 0), (0, 1, 40), (0, 2, 100), (0, 3, 300), (0, 4, 1200), (9, 4, 12000)];
+
+    for &(level, num_cleared_rows, expected_score) in &cases {
+        let score = score_of(&mut NesScoring::new(), &[make_event(level, num_cleared_rows)]);
+
+        assert_eq!(score, expected_score);
+    }
+}
+
+#[test]
+fn nes_pays_a_point_per_row_pushed_down() {
+    let event = LockEvent {
+        soft_drop_distance: 12,
+        hard_drop_distance: 5,
+        ..make_event(3, 1)
+    };
+
+    assert_eq!(score_of(&mut NesScoring::new(), &[event]), 160 + 12);
+}
+
+#[test]
+fn nes_ignores_spins_combos_and_back_to_back() {
+    let event = LockEvent {
+        spin: Spin::Full,
+        combo: 3,
+        is_back_to_back: true,
+        ..make_event(0, 4)
+    };
+
+    assert_eq!(score_of(&mut NesScoring::new(), &[event]), 1200);
+}
+
+#[test]
+fn nes_score_accumulates() {
+    let events = [make_event(0, 1), make_event(0, 0), make_event(1, 2)];
+
+    assert_eq!(score_of(&mut NesScoring::new(), &events), 40 + 200);
+}
+
+#[test]
+fn guideline_line_clears_are_multiplied_by_the_level() {
+    let cases = [(1, 0, 0), (1, 1, 100), (1, 2, 300), (1, 3, 500), (1, 4, 800), (5, 4, 4000)];
+
+    for &(level, num_cleared_rows, expected_score) in &cases {
+        let score = score_of(&mut GuidelineScoring::new(), &[make_event(level, num_cleared_rows)]);
+
+        assert_eq!(score, expected_score);
+    }
+}
+
+#[test]
+fn guideline_t_spins_pay_a_bonus() {
+    let cases = [(0, 400), (1, 800), (2, 1200), (3, 1600)];
+
+    for &(num_cleared_rows, expected_score) in &cases {
+        let event = LockEvent { spin: Spin::Full, ..make_event(1, num_cleared_rows) };
+
+        assert_eq!(score_of(&mut GuidelineScoring::new(), &[event]), expected_score);
+    }
+}
+
+#[test]
+fn guideline_mini_t_spins_pay_a_smaller_bonus() {
+    let cases = [(0, 100), (1, 200), (2, 400)];
+
+    for &(num_cleared_rows, expected_score) in &cases {
+        let event = LockEvent { spin: Spin::Mini, ..make_event(2, num_cleared_rows) };
+
+        assert_eq!(score_of(&mut GuidelineScoring::new(), &[event]), expected_score * 2);
+    }
+}
+
+#[test]
+fn guideline_back_to_back_difficult_clears_pay_half_more() {
+    let tetris = LockEvent { is_back_to_back: true, ..make_event(1, 4) };
+    let t_spin_double = LockEvent { spin: Spin::Full, is_back_to_back: true, ..make_event(1, 2) };
+    let mini_single = LockEvent { spin: Spin::Mini, is_back_to_back: true, ..make_event(1, 1) };
+
+    assert_eq!(score_of(&mut GuidelineScoring::new(), &[tetris]), 1200);
+    assert_eq!(score_of(&mut GuidelineScoring::new(), &[t_spin_double]), 1800);
+    assert_eq!(score_of(&mut GuidelineScoring::new(), &[mini_single]), 300);
+}
+
+#[test]
+fn guideline_back_to_back_does_not_apply_to_easy_clears() {
+    let triple = LockEvent { is_back_to_back: true, ..make_event(1, 3) };
+    let t_spin_without_lines = LockEvent { spin: Spin::Full, is_back_to_back: true, ..make_event(1, 0) };
+
+    assert_eq!(score_of(&mut GuidelineScoring::new(), &[triple]), 500);
+    assert_eq!(score_of(&mut GuidelineScoring::new(), &[t_spin_without_lines]), 400);
+}
+
+#[test]
+fn guideline_combos_pay_fifty_points_per_step() {
+    let events = [
+        LockEvent { combo: 0, ..make_event(2, 1) },
+        LockEvent { combo: 1, ..make_event(2, 1) },
+        LockEvent { combo: 2, ..make_event(2, 2) },
+    ];
+
+    assert_eq!(score_of(&mut GuidelineScoring::new(), &events), 200 + (200 + 100) + (600 + 200));
+}
+
+#[test]
+fn guideline_drops_pay_per_row() {
+    let event = LockEvent {
+        soft_drop_distance: 7,
+        hard_drop_distance: 10,
+        ..make_event(4, 0)
+    };
+
+    assert_eq!(score_of(&mut GuidelineScoring::new(), &[event]), 7 + 20);
+}
+
+#[test]
+fn nes_chain_clears_pay_more_further_down_the_chain() {
+    let mut scoring = NesScoring::new();
+
+    scoring.on_chain_clear(&ChainEvent { level: 0, chain: 1, num_cleared_rows: 1 });
+    assert_eq!(scoring.get_score(), 80);
+
+    scoring.on_chain_clear(&ChainEvent { level: 1, chain: 2, num_cleared_rows: 2 });
+    assert_eq!(scoring.get_score(), 80 + 600);
+}
+
+#[test]
+fn guideline_chain_clears_pay_more_further_down_the_chain() {
+    let mut scoring = GuidelineScoring::new();
+
+    scoring.on_chain_clear(&ChainEvent { level: 1, chain: 1, num_cleared_rows: 1 });
+    assert_eq!(scoring.get_score(), 200);
+
+    scoring.on_chain_clear(&ChainEvent { level: 2, chain: 2, num_cleared_rows: 4 });
+    assert_eq!(scoring.get_score(), 200 + 4800);
+}
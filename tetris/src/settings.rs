@@ -1,22 +1,100 @@
+use sfml::window::Key;
+use super::core::{Controls, Handling, HandlingDelay, LockResetPolicy};
 use super::gravity::Gravity;
+use super::mode::GameModeKind;
+use super::piece::ColorSchemeKind;
+use super::randomizer::RandomizerKind;
 use super::rotations::{self, RotationSystem};
+use super::scoring::ScoringKind;
+use super::speed::GravityCurveKind;
 
 pub const TILE_SIZE: usize = 18;
 pub const TILE_SCALING: f32 = 1.5;
 
-pub const BOARD_VIEW_POSITION_X: f32 = 10.;
+pub const BOARD_VIEW_POSITION_X: f32 = 160.;
 pub const BOARD_VIEW_POSITION_Y: f32 = 20.;
 
+pub const HOLD_VIEW_POSITION_X: f32 = 10.;
+pub const HOLD_VIEW_POSITION_Y: f32 = 20.;
+
+pub const NEXT_VIEW_POSITION_X: f32 = 580.;
+pub const NEXT_VIEW_POSITION_Y: f32 = 20.;
+pub const NEXT_TILE_SCALING: f32 = 1.;
+
+// While soft dropping, the piece falls at least one row every this many frames
+pub const SOFT_DROP_DELAY_FRAMES: u64 = 2;
+
+// The next-piece queue shows between this many pieces and the maximum below
+pub const MIN_NUM_NEXT_PIECES: usize = 1;
+pub const MAX_NUM_NEXT_PIECES: usize = 6;
+
+// Side boxes (e.g. the hold box) fit this many tiles in each dimension
+pub const PREVIEW_BOX_NUM_TILES: usize = 5;
+
 pub struct Settings {
+    // Columns and visible rows
     pub board_size: (usize, usize),
+    // Hidden rows above the visible ones
+    pub buffer_rows: usize,
     pub gravity: Gravity,
     pub rotation_system: RotationSystem,
+    pub randomizer: RandomizerKind,
+    pub color_scheme: ColorSchemeKind,
+    pub seed: u64,
+    pub num_next_pieces: usize,
+    pub controls: Controls,
+    pub handling: Handling,
+    pub lock_delay_frames: u64,
+    pub lock_reset_policy: LockResetPolicy,
+    pub gravity_curve: GravityCurveKind,
+    pub level: usize,
+    pub entry_delay_frames: u64,
+    pub line_clear_delay_frames: u64,
+    pub initial_rotation: bool,
+    pub initial_hold: bool,
+    pub scoring: ScoringKind,
+    pub all_spin: bool,
+    pub game_mode: GameModeKind,
+    // Marathon is won after completing this level, or endless when None
+    pub marathon_end_level: Option<usize>,
 }
 
 pub fn make_default_settings() -> Settings {
     Settings {
         board_size: (15, 20),
+        buffer_rows: 20,
         gravity: Gravity::Naive,
         rotation_system: rotations::build_nintendo_rotation_system(),
+        randomizer: RandomizerKind::Bag,
+        color_scheme: ColorSchemeKind::Guideline,
+        seed: rand::random(),
+        num_next_pieces: 3,
+        controls: Controls {
+            move_left: Key::Left,
+            move_right: Key::Right,
+            rotate_clockwise: Key::S,
+            rotate_counterclockwise: Key::A,
+            rotate_half: Key::D,
+            hold: Key::LShift,
+            soft_drop: Key::Down,
+            hard_drop: Key::Space,
+        },
+        handling: Handling {
+            das: HandlingDelay::Frames(10.),
+            arr: HandlingDelay::Frames(2.),
+            preserve_das_charge: true,
+        },
+        lock_delay_frames: 30,
+        lock_reset_policy: LockResetPolicy::MoveReset,
+        gravity_curve: GravityCurveKind::Guideline,
+        level: 1,
+        entry_delay_frames: 0,
+        line_clear_delay_frames: 0,
+        initial_rotation: true,
+        initial_hold: true,
+        scoring: ScoringKind::Guideline,
+        all_spin: false,
+        game_mode: GameModeKind::FreePlay,
+        marathon_end_level: Some(15),
     }
 }
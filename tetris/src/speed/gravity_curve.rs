@@ -0,0 +1,11 @@
+// 20G: the piece crosses a whole standard board in a single frame. Curves
+// reaching it are treated as instant gravity, whatever the board height
+pub const MAX_CELLS_PER_FRAME: f32 = 20.;
+
+/**
+ * How fast the active piece falls at a given level, in cells per frame.
+ * Values below one mean the piece needs several frames to fall by a row.
+ */
+pub trait GravityCurve {
+    fn get_cells_per_frame(&self, level: usize) -> f32;
+}
@@ -0,0 +1,6 @@
+pub enum GravityCurveKind {
+    Nes,
+    Guideline,
+    Tgm,
+    Instant,
+}
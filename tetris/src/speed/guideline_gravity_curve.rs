@@ -0,0 +1,27 @@
+use super::{GravityCurve, MAX_CELLS_PER_FRAME};
+
+const FRAMES_PER_SECOND: f32 = 60.;
+
+/**
+ * Fall speeds of the guideline, where levels start at 1. The time a piece
+ * takes to fall by a row is `(0.8 - ((level - 1) * 0.007)) ^ (level - 1)`
+ * seconds, capped at 20G.
+ */
+pub struct GuidelineGravityCurve;
+
+impl GravityCurve for GuidelineGravityCurve {
+    fn get_cells_per_frame(&self, level: usize) -> f32 {
+        let exponent = level.max(1) as f32 - 1.;
+        let base = 0.8 - exponent * 0.007;
+
+        // Past level 114 the base becomes negative, long after hitting 20G
+        if base <= 0. {
+            return MAX_CELLS_PER_FRAME;
+        }
+
+        let seconds_per_row = base.powf(exponent);
+        let cells_per_frame = 1. / (seconds_per_row * FRAMES_PER_SECOND);
+
+        cells_per_frame.min(MAX_CELLS_PER_FRAME)
+    }
+}
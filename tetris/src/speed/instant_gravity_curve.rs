@@ -0,0 +1,13 @@
+use super::{GravityCurve, MAX_CELLS_PER_FRAME};
+
+/**
+ * 20G at every level: pieces reach the ground as soon as they spawn and
+ * after every move or rotation.
+ */
+pub struct InstantGravityCurve;
+
+impl GravityCurve for InstantGravityCurve {
+    fn get_cells_per_frame(&self, _level: usize) -> f32 {
+        MAX_CELLS_PER_FRAME
+    }
+}
@@ -0,0 +1,16 @@
+mod gravity_curve;
+mod gravity_curve_kind;
+mod guideline_gravity_curve;
+mod instant_gravity_curve;
+mod nes_gravity_curve;
+mod tgm_gravity_curve;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::gravity_curve::{GravityCurve, MAX_CELLS_PER_FRAME};
+pub use self::gravity_curve_kind::GravityCurveKind;
+pub use self::guideline_gravity_curve::GuidelineGravityCurve;
+pub use self::instant_gravity_curve::InstantGravityCurve;
+pub use self::nes_gravity_curve::NesGravityCurve;
+pub use self::tgm_gravity_curve::TgmGravityCurve;
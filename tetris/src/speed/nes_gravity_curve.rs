@@ -0,0 +1,22 @@
+use super::GravityCurve;
+
+// Frames per row for levels 0 to 28 on NTSC; from level 29 on, pieces fall
+// a row every frame
+const FRAMES_PER_ROW: [usize; 29] = [
+    48, 43, 38, 33, 28, 23, 18, 13, 8, 6,
+    5, 5, 5, 4, 4, 4, 3, 3, 3, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2,
+];
+
+/**
+ * Fall speeds of the NES version, where levels start at 0.
+ */
+pub struct NesGravityCurve;
+
+impl GravityCurve for NesGravityCurve {
+    fn get_cells_per_frame(&self, level: usize) -> f32 {
+        let frames_per_row = FRAMES_PER_ROW.get(level).cloned().unwrap_or(1);
+
+        1. / frames_per_row as f32
+    }
+}
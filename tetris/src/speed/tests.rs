@@ -0,0 +1,84 @@
+use super::{
+    GravityCurve,
+    GuidelineGravityCurve,
+    NesGravityCurve,
+    TgmGravityCurve,
+    MAX_CELLS_PER_FRAME,
+};
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-4,
+        "expected {} cells per frame, got {}",
+        expected,
+        actual,
+    );
+}
+
+#[test]
+fn nes_curve_follows_the_frame_table() {
+    let curve = NesGravityCurve;
+    let cases = [(0, 48), (1, 43), (8, 8), (9, 6), (12, 5), (13, 4), (18, 3), (19, 2), (28, 2)];
+
+    for &(level, frames_per_row) in &cases {
+        assert_close(curve.get_cells_per_frame(level), 1. / frames_per_row as f32);
+    }
+}
+
+#[test]
+fn nes_curve_falls_a_row_per_frame_from_level_29() {
+    let curve = NesGravityCurve;
+
+    for &level in &[29, 30, 100] {
+        assert_close(curve.get_cells_per_frame(level), 1.);
+    }
+}
+
+#[test]
+fn guideline_curve_follows_the_formula() {
+    let curve = GuidelineGravityCurve;
+    // Seconds per row from the guideline table
+    let cases = [(1, 1.), (2, 0.793), (5, 0.355_20), (10, 0.064_15), (15, 0.007_05)];
+
+    for &(level, seconds_per_row) in &cases {
+        let expected = 1. / (seconds_per_row * 60.);
+        let actual = curve.get_cells_per_frame(level);
+
+        assert!((actual - expected).abs() / expected < 0.01);
+    }
+}
+
+#[test]
+fn guideline_curve_is_capped_at_20g() {
+    let curve = GuidelineGravityCurve;
+
+    for &level in &[20, 50, 200] {
+        assert_close(curve.get_cells_per_frame(level), MAX_CELLS_PER_FRAME);
+    }
+}
+
+#[test]
+fn tgm_curve_follows_the_internal_gravity_table() {
+    let curve = TgmGravityCurve;
+    let cases = [
+        (0, 4),
+        (29, 4),
+        (30, 6),
+        (199, 144),
+        (200, 4),
+        (251, 256),
+        (420, 1024),
+        (499, 768),
+        (500, 5120),
+        (999, 5120),
+    ];
+
+    for &(level, internal_gravity) in &cases {
+        assert_close(curve.get_cells_per_frame(level), internal_gravity as f32 / 256.);
+    }
+}
+
+#[test]
+fn tgm_curve_reaches_20g() {
+    assert_close(TgmGravityCurve.get_cells_per_frame(500), MAX_CELLS_PER_FRAME);
+}
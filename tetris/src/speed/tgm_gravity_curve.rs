@@ -0,0 +1,55 @@
+use super::GravityCurve;
+
+// Internal gravity is counted in 1/256ths of a cell per frame
+const INTERNAL_UNITS_PER_CELL: f32 = 256.;
+
+// (first level, internal gravity) pairs, sorted by level
+const INTERNAL_GRAVITY: [(usize, usize); 30] = [
+    (0, 4),
+    (30, 6),
+    (35, 8),
+    (40, 10),
+    (50, 12),
+    (60, 16),
+    (70, 32),
+    (80, 48),
+    (90, 64),
+    (100, 80),
+    (120, 96),
+    (140, 112),
+    (160, 128),
+    (170, 144),
+    (200, 4),
+    (220, 32),
+    (230, 64),
+    (233, 96),
+    (236, 128),
+    (239, 160),
+    (243, 192),
+    (247, 224),
+    (251, 256),
+    (300, 512),
+    (330, 768),
+    (360, 1024),
+    (400, 1280),
+    (420, 1024),
+    (450, 768),
+    (500, 5120),
+];
+
+/**
+ * Fall speeds of the first Tetris The Grand Master, whose levels go from 0
+ * to 999 and reach 20G at level 500.
+ */
+pub struct TgmGravityCurve;
+
+impl GravityCurve for TgmGravityCurve {
+    fn get_cells_per_frame(&self, level: usize) -> f32 {
+        let (_, internal_gravity) = INTERNAL_GRAVITY.iter()
+            .rev()
+            .find(|(first_level, _)| *first_level <= level)
+            .unwrap();
+
+        *internal_gravity as f32 / INTERNAL_UNITS_PER_CELL
+    }
+}